@@ -0,0 +1,305 @@
+//! Benchmarks the parsing and both parts of every day, against the examples
+//! and, when it's available, the real puzzle input, along with a few
+//! alternative algorithms on generated inputs.
+//!
+//! Each run is compared against a baseline, `previous` by default, which is
+//! then overwritten with the new results. The report is also written to
+//! `bench_output.txt`.
+//!
+//! ```sh
+//! cargo bench                                # compare against the previous run
+//! cargo bench -- day04                       # only the groups matching `day04`
+//! cargo bench -- --save-baseline before      # also save the results as `before`
+//! cargo bench -- --baseline before           # compare against `before`
+//! ```
+
+use anyhow::{Context, Result, bail};
+use aoc_for_rustaceans::{
+    days::{
+        CALENDAR,
+        day04::{self, AccessRule, RemovalMode},
+    },
+    input,
+    runner::{Entry, Part},
+    timing::{Elapsed, Timing},
+};
+use std::{collections::HashMap, env, fs, path::PathBuf, time::Duration};
+
+const OUTPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench_output.txt");
+
+const PREVIOUS_BASELINE: &str = "previous";
+
+/// Time spent measuring each step, used to pick how many runs to do.
+const TARGET_TIME: Duration = Duration::from_millis(500);
+const MIN_RUNS: usize = 5;
+const MAX_RUNS: usize = 10_000;
+
+/// Relative change of the median under which a difference is just noise.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+#[derive(Debug, Default)]
+struct Args {
+    /// Set by `cargo bench`, unlike `cargo test --benches` which only checks
+    /// that the benchmarks build and start.
+    bench: bool,
+    filter: Option<String>,
+    baseline: Option<String>,
+    save_baseline: Option<String>,
+}
+
+impl Args {
+    fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+        let mut parsed = Args::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                // passed by `cargo bench`
+                "--bench" => parsed.bench = true,
+                "--baseline" => {
+                    parsed.baseline = Some(args.next().context("missing baseline name")?);
+                }
+                "--save-baseline" => {
+                    parsed.save_baseline = Some(args.next().context("missing baseline name")?);
+                }
+                filter if !filter.starts_with('-') && parsed.filter.is_none() => {
+                    parsed.filter = Some(filter.to_string());
+                }
+                _ => bail!("unexpected argument `{arg}`"),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+struct Bench {
+    name: String,
+    timing: Timing,
+}
+
+fn bench(
+    benches: &mut Vec<Bench>,
+    group: &str,
+    entry: &Entry,
+    input: &str,
+    parts: &[Part],
+) -> Result<()> {
+    let warm_up = entry.measure(input, parts, 1)?;
+    let slowest = warm_up
+        .parts
+        .iter()
+        .map(|(_, _, timing)| timing.median)
+        .fold(warm_up.parse.median, Duration::max);
+
+    let runs = (TARGET_TIME.as_nanos() / slowest.as_nanos().max(1)) as usize;
+    let measurement = entry.measure(input, parts, runs.clamp(MIN_RUNS, MAX_RUNS))?;
+
+    benches.push(Bench {
+        name: format!("{group}/parse"),
+        timing: measurement.parse,
+    });
+
+    for (part, _, timing) in measurement.parts {
+        let step = match part {
+            Part::One => "part_1",
+            Part::Two => "part_2",
+        };
+
+        benches.push(Bench {
+            name: format!("{group}/{step}"),
+            timing,
+        });
+    }
+
+    Ok(())
+}
+
+fn bench_fn<T>(benches: &mut Vec<Bench>, name: &str, mut f: impl FnMut() -> T) -> Result<()> {
+    let (_, warm_up) = Timing::measure(1, || Ok(f()))?;
+    let runs = (TARGET_TIME.as_nanos() / warm_up.median.as_nanos().max(1)) as usize;
+    let (_, timing) = Timing::measure(runs.clamp(MIN_RUNS, MAX_RUNS), || Ok(f()))?;
+
+    benches.push(Bench {
+        name: name.to_string(),
+        timing,
+    });
+
+    Ok(())
+}
+
+/// Day 4 removal with a work queue against rescanning the whole diagram.
+fn bench_day04_removal(benches: &mut Vec<Bench>) -> Result<()> {
+    let diagram = day04::generate_diagram(2000, 2000, 70, 2025);
+    let rule = AccessRule::default();
+
+    bench_fn(benches, "day04/generated_2000x2000/queue", || {
+        day04::removal_waves(&diagram, RemovalMode::Sequential, &rule)
+    })?;
+    bench_fn(benches, "day04/generated_2000x2000/rescan", || {
+        day04::removal_waves_by_rescanning(&diagram, RemovalMode::Sequential, &rule)
+    })
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    let target_dir = env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target"),
+        PathBuf::from,
+    );
+
+    target_dir.join("aoc_bench").join(format!("{name}.tsv"))
+}
+
+fn load_baseline(name: &str) -> Result<HashMap<String, Duration>> {
+    let path = baseline_path(name);
+
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(HashMap::new());
+    };
+
+    content
+        .lines()
+        .map(|line| {
+            let (bench, nanos) = line
+                .split_once('\t')
+                .with_context(|| format!("malformed baseline `{}`", path.display()))?;
+            let nanos = nanos
+                .parse()
+                .with_context(|| format!("malformed baseline `{}`", path.display()))?;
+
+            Ok((bench.to_string(), Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+fn save_baseline(name: &str, benches: &[Bench]) -> Result<()> {
+    let path = baseline_path(name);
+    let mut baseline = load_baseline(name)?;
+
+    for bench in benches {
+        baseline.insert(bench.name.clone(), bench.timing.median);
+    }
+
+    let mut lines: Vec<String> = baseline
+        .iter()
+        .map(|(bench, median)| format!("{bench}\t{}", median.as_nanos()))
+        .collect();
+    lines.sort();
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(&path, lines.join("\n") + "\n")
+        .with_context(|| format!("failed to save baseline `{}`", path.display()))
+}
+
+fn change(median: Duration, baseline: Option<&Duration>) -> String {
+    let Some(baseline) = baseline else {
+        return "new".to_string();
+    };
+
+    let change = (median.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64();
+    let verdict = if change > NOISE_THRESHOLD {
+        "regressed"
+    } else if change < -NOISE_THRESHOLD {
+        "improved"
+    } else {
+        "no change"
+    };
+
+    format!("{:+.1}% ({verdict})", change * 100.0)
+}
+
+fn report(benches: &[Bench], baseline: &HashMap<String, Duration>) -> String {
+    let rows: Vec<[String; 5]> = benches
+        .iter()
+        .map(|bench| {
+            [
+                bench.name.clone(),
+                Elapsed(bench.timing.median).to_string(),
+                Elapsed(bench.timing.min).to_string(),
+                Elapsed(bench.timing.max).to_string(),
+                change(bench.timing.median, baseline.get(&bench.name)),
+            ]
+        })
+        .collect();
+
+    let header = ["Benchmark", "Median", "Min", "Max", "Change"].map(String::from);
+
+    let mut widths = [0; 5];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            let [name, median, min, max, change] = row;
+            let [w0, w1, w2, w3, _] = widths;
+            format!("{name:<w0$}  {median:>w1$}  {min:>w2$}  {max:>w3$}  {change}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args(env::args().skip(1))?;
+    if !args.bench {
+        return Ok(());
+    }
+    let matches = |group: &str| args.filter.as_ref().is_none_or(|f| group.contains(f));
+
+    let mut benches = Vec::new();
+    let mut skipped = Vec::new();
+
+    for entry in &CALENDAR {
+        for (n, part) in Part::BOTH.into_iter().enumerate() {
+            let group = format!("day{:02}/example_{}", entry.day, n + 1);
+            if matches(&group) {
+                bench(
+                    &mut benches,
+                    &group,
+                    entry,
+                    entry.example(part).input,
+                    &[part],
+                )?;
+            }
+        }
+
+        let group = format!("day{:02}/input", entry.day);
+        if matches(&group) {
+            match input::load(entry.day, None) {
+                Ok(input) => bench(&mut benches, &group, entry, &input, &Part::BOTH)?,
+                Err(_) => skipped.push(group),
+            }
+        }
+    }
+
+    if matches("day04/generated_2000x2000") {
+        bench_day04_removal(&mut benches)?;
+    }
+
+    let baseline_name = args.baseline.as_deref().unwrap_or(PREVIOUS_BASELINE);
+    let baseline = load_baseline(baseline_name)?;
+
+    let mut output = format!(
+        "Baseline: {baseline_name}\n\n{}\n",
+        report(&benches, &baseline)
+    );
+    if !skipped.is_empty() {
+        output += &format!("\nSkipped (missing puzzle input): {}\n", skipped.join(", "));
+    }
+
+    print!("{output}");
+    fs::write(OUTPUT_FILE, output).context("failed to write bench_output.txt")?;
+
+    save_baseline(PREVIOUS_BASELINE, &benches)?;
+    if let Some(name) = &args.save_baseline {
+        save_baseline(name, &benches)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,398 @@
+use crate::grid::Grid;
+use anyhow::{Result, ensure};
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// What a cell of a [`Frame`] looks like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shade {
+    Empty,
+    Filled,
+    /// Something that just changed.
+    Highlighted,
+}
+
+impl Shade {
+    const ALL: [Shade; 3] = [Shade::Empty, Shade::Filled, Shade::Highlighted];
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Shade::Empty => [0x0f, 0x0f, 0x23],
+            Shade::Filled => [0xcc, 0xcc, 0xcc],
+            Shade::Highlighted => [0xff, 0x44, 0x44],
+        }
+    }
+
+    fn ansi(self) -> &'static str {
+        match self {
+            Shade::Empty => ".",
+            Shade::Filled => "@",
+            Shade::Highlighted => "\x1b[1;31m@\x1b[0m",
+        }
+    }
+}
+
+/// Plain text, without the ANSI colours.
+impl fmt::Display for Shade {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shade::Empty => write!(f, "."),
+            Shade::Filled | Shade::Highlighted => write!(f, "@"),
+        }
+    }
+}
+
+/// A step of an animation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Grid<Shade>,
+    pub caption: String,
+}
+
+/// Plays `frames` in the terminal, redrawing the screen every `delay`.
+pub fn play(frames: &[Frame], delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    for frame in frames {
+        // clear the screen and go back to its top left corner
+        write!(out, "\x1b[2J\x1b[H")?;
+
+        for row in frame.grid.rows() {
+            for shade in row {
+                write!(out, "{}", shade.ansi())?;
+            }
+            writeln!(out)?;
+        }
+
+        writeln!(out, "\n{}", frame.caption)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+/// Draws `frame` as an SVG image, each cell a square of `cell_size` pixels.
+pub fn svg(frame: &Frame, cell_size: usize) -> String {
+    let (width, height) = (
+        frame.grid.width() * cell_size,
+        frame.grid.height() * cell_size,
+    );
+    let color = |shade: Shade| {
+        let [r, g, b] = shade.rgb();
+        format!("#{r:02x}{g:02x}{b:02x}")
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+    svg += &format!(
+        "<title>{}</title>\n<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+        escape_xml(&frame.caption),
+        color(Shade::Empty)
+    );
+
+    for ((x, y), &shade) in frame.grid.iter() {
+        if shade != Shade::Empty {
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{cell_size}\" height=\"{cell_size}\" fill=\"{}\"/>\n",
+                x * cell_size,
+                y * cell_size,
+                color(shade)
+            );
+        }
+    }
+
+    svg + "</svg>\n"
+}
+
+/// `text` with the characters XML gives a meaning to escaped.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Encodes `frames` as an animated GIF looping forever, each cell a square of
+/// `cell_size` pixels and each frame shown for `delay`.
+pub fn gif(frames: &[Frame], cell_size: usize, delay: Duration) -> Result<Vec<u8>> {
+    let first = frames.first().map(|frame| &frame.grid);
+    let (width, height) = first.map_or((0, 0), |grid| (grid.width(), grid.height()));
+    ensure!(
+        frames
+            .iter()
+            .all(|frame| (frame.grid.width(), frame.grid.height()) == (width, height)),
+        "every frame must have the same size"
+    );
+
+    let too_large = || format!("a GIF can't be larger than {} pixels", u16::MAX);
+    let pixel_width = u16::try_from(width * cell_size).map_err(|_| anyhow::anyhow!(too_large()))?;
+    let pixel_height =
+        u16::try_from(height * cell_size).map_err(|_| anyhow::anyhow!(too_large()))?;
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(pixel_width.to_le_bytes());
+    gif.extend(pixel_height.to_le_bytes());
+    // a global colour table of 4 colours, 2 bits per pixel
+    gif.extend([0x91, 0, 0]);
+    for shade in Shade::ALL {
+        gif.extend(shade.rgb());
+    }
+    gif.extend([0, 0, 0]);
+
+    // loop forever
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        gif.extend([0x21, 0xf9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(pixel_width.to_le_bytes());
+        gif.extend(pixel_height.to_le_bytes());
+        gif.push(0x00);
+
+        let pixels = (0..height * cell_size).flat_map(|y| {
+            (0..width * cell_size).map(move |x| {
+                let shade = frame.grid[(x / cell_size, y / cell_size)];
+                Shade::ALL.iter().position(|&s| s == shade).unwrap_or(0) as u8
+            })
+        });
+
+        gif.push(MIN_CODE_SIZE);
+        for block in lzw(pixels).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3b);
+
+    Ok(gif)
+}
+
+/// Bits per pixel given to the LZW compression, the minimum GIF allows.
+const MIN_CODE_SIZE: u8 = 2;
+
+const MAX_CODE: u16 = 4095;
+
+/// Packs codes of varying widths, least significant bits first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses GIF pixels with the variable-width LZW it expects.
+///
+/// The code size grows one code late compared to the dictionary, as decoders
+/// only learn an entry when they read the code after it.
+fn lzw(pixels: impl IntoIterator<Item = u8>) -> Vec<u8> {
+    let clear = 1u16 << MIN_CODE_SIZE;
+    let end = clear + 1;
+    let initial_size = u32::from(MIN_CODE_SIZE) + 1;
+
+    let mut out = BitWriter::default();
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = initial_size;
+
+    out.write(clear, size);
+
+    let mut pixels = pixels.into_iter();
+    let Some(first) = pixels.next() else {
+        out.write(end, size);
+        return out.finish();
+    };
+    let mut prefix = u16::from(first);
+
+    for pixel in pixels {
+        if let Some(&code) = dictionary.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+
+        out.write(prefix, size);
+        dictionary.insert((prefix, pixel), next);
+        next += 1;
+
+        if next - 1 == 1 << size && size < 12 {
+            size += 1;
+        }
+
+        if next > MAX_CODE {
+            out.write(clear, size);
+            dictionary.clear();
+            next = end + 1;
+            size = initial_size;
+        }
+
+        prefix = u16::from(pixel);
+    }
+
+    out.write(prefix, size);
+    // the decoder adds an entry for the last code too
+    if next == 1 << size && size < 12 {
+        size += 1;
+    }
+    out.write(end, size);
+
+    out.finish()
+}
+
+/// Decodes the image data of a GIF frame, the way decoders read [`lzw`].
+#[cfg(test)]
+fn unlzw(bytes: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << MIN_CODE_SIZE;
+    let end = clear + 1;
+    let initial_size = u32::from(MIN_CODE_SIZE) + 1;
+
+    let mut codes = Vec::new();
+    let (mut buffer, mut bits, mut bytes) = (0u32, 0, bytes.iter());
+    let mut size = initial_size;
+    let mut dictionary: Vec<Vec<u8>> = Vec::new();
+    let reset = |dictionary: &mut Vec<Vec<u8>>| {
+        *dictionary = (0..=end).map(|code| vec![code as u8]).collect();
+    };
+    reset(&mut dictionary);
+    let mut previous: Option<u16> = None;
+
+    loop {
+        while bits < size {
+            buffer |= u32::from(*bytes.next().expect("missing end code")) << bits;
+            bits += 8;
+        }
+        let code = (buffer & ((1 << size) - 1)) as u16;
+        buffer >>= size;
+        bits -= size;
+
+        if code == clear {
+            reset(&mut dictionary);
+            size = initial_size;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            return codes;
+        }
+
+        let entry = match (dictionary.get(usize::from(code)), previous) {
+            (Some(entry), _) => entry.clone(),
+            (None, Some(previous)) => {
+                let mut entry = dictionary[usize::from(previous)].clone();
+                entry.push(entry[0]);
+                entry
+            }
+            (None, None) => panic!("unknown first code {code}"),
+        };
+        codes.extend(&entry);
+
+        if let Some(previous) = previous
+            && dictionary.len() <= usize::from(MAX_CODE)
+        {
+            let mut added = dictionary[usize::from(previous)].clone();
+            added.push(entry[0]);
+            dictionary.push(added);
+
+            if dictionary.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+        }
+        previous = Some(code);
+    }
+}
+
+#[test]
+fn test_lzw() {
+    let mut rng = crate::rng::Xorshift::new(0x2025_1204);
+    let mut random = |bound: u64| rng.below(bound) as u8;
+
+    let noisy: Vec<u8> = (0..20_000).map(|_| random(3)).collect();
+    let runs: Vec<u8> = (0..50_000).map(|i| (i / 97 % 3) as u8).collect();
+
+    for pixels in [vec![], vec![2], vec![1; 10], noisy, runs] {
+        assert_eq!(unlzw(&lzw(pixels.iter().copied())), pixels);
+    }
+}
+
+#[test]
+fn test_gif() -> Result<()> {
+    let grid = Grid::from_str(0, ".@\n@.\n", "a shade", |c| match c {
+        '.' => Some(Shade::Empty),
+        '@' => Some(Shade::Filled),
+        _ => None,
+    })?;
+    let frame = Frame {
+        grid,
+        caption: "Round 1".to_string(),
+    };
+
+    let gif = gif(&[frame.clone(), frame], 3, Duration::from_millis(250))?;
+    assert!(gif.starts_with(b"GIF89a\x06\x00\x06\x00"));
+    assert!(gif.ends_with(b"\x3b"));
+    // one graphic control block per frame, with a delay of 25 hundredths
+    assert_eq!(
+        gif.windows(6)
+            .filter(|w| *w == [0x21, 0xf9, 0x04, 0x00, 25, 0])
+            .count(),
+        2
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_svg() -> Result<()> {
+    let grid = Grid::from_str(0, ".@\n@.\n", "a shade", |c| match c {
+        '.' => Some(Shade::Empty),
+        '@' => Some(Shade::Highlighted),
+        _ => None,
+    })?;
+    let frame = Frame {
+        grid,
+        caption: "Round 1".to_string(),
+    };
+
+    let svg = svg(&frame, 4);
+    assert!(svg.contains("viewBox=\"0 0 8 8\""));
+    assert!(svg.contains("<title>Round 1</title>"));
+    assert!(svg.contains("<rect x=\"4\" y=\"0\" width=\"4\" height=\"4\" fill=\"#ff4444\"/>"));
+    assert_eq!(svg.matches("<rect").count(), 3);
+
+    let frame = Frame {
+        caption: "Round <1> & more".to_string(),
+        ..frame
+    };
+    let svg = crate::animation::svg(&frame, 4);
+    assert!(svg.contains("<title>Round &lt;1&gt; &amp; more</title>"));
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use crate::{
+    input,
+    runner::{Entry, Part},
+};
+use anyhow::{Context, Result, bail};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers");
+
+/// The accepted answers of a day, stored in `answers/day_NN.toml`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(day: u8) -> Result<Self> {
+        let path = answers_path(day);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read answers `{}`", path.display()))?;
+
+        Self::from_toml(&content)
+            .with_context(|| format!("failed to parse answers `{}`", path.display()))
+    }
+
+    pub fn save(&self, day: u8) -> Result<()> {
+        let path = answers_path(day);
+
+        fs::create_dir_all(ANSWERS_DIR)?;
+        fs::write(&path, self.to_toml(day))
+            .with_context(|| format!("failed to write answers `{}`", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+
+    fn from_toml(content: &str) -> Result<Self> {
+        let mut answers = Self::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("line {}: expected `key = \"value\"`", i + 1))?;
+            let value = value
+                .trim()
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .with_context(|| format!("line {}: expected a quoted answer", i + 1))?;
+
+            let value = unescape(value)
+                .with_context(|| format!("line {}: expected a quoted answer", i + 1))?;
+
+            match key.trim() {
+                "part_1" => answers.set(Part::One, value),
+                "part_2" => answers.set(Part::Two, value),
+                key => bail!("line {}: unknown key `{key}`", i + 1),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn to_toml(&self, day: u8) -> String {
+        let mut toml = format!("# Accepted answers of day {day}\n");
+
+        if let Some(answer) = &self.part_1 {
+            toml += &format!("part_1 = \"{}\"\n", escape(answer));
+        }
+        if let Some(answer) = &self.part_2 {
+            toml += &format!("part_2 = \"{}\"\n", escape(answer));
+        }
+
+        toml
+    }
+}
+
+/// `answer` as the content of a TOML basic string.
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The answer in the content of a TOML basic string, `None` if it has an
+/// unescaped quote or an escape [`escape`] doesn't write.
+fn unescape(value: &str) -> Option<String> {
+    let mut answer = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => answer.push(match chars.next()? {
+                '\\' => '\\',
+                '"' => '"',
+                'n' => '\n',
+                _ => return None,
+            }),
+            c => answer.push(c),
+        }
+    }
+
+    Some(answer)
+}
+
+fn answers_path(day: u8) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("day_{day:02}.toml"))
+}
+
+/// The answer of a part to the puzzle input, next to the accepted one, or to
+/// an example, next to its output.
+#[derive(Debug)]
+pub struct Outcome {
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl Outcome {
+    pub fn is_correct(&self) -> bool {
+        self.expected.as_ref().is_none_or(|e| *e == self.actual)
+    }
+}
+
+/// Solves `parts` of their examples and compares them to the outputs given in
+/// the puzzle description.
+pub fn verify_examples(entry: &Entry, parts: &[Part]) -> Result<Vec<Outcome>> {
+    parts
+        .iter()
+        .map(|&part| {
+            let example = entry.example(part);
+            let actual = entry.solve(example.input, &[part])?.remove(0);
+
+            Ok(Outcome {
+                part,
+                expected: Some(example.output.to_string()),
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Solves `parts` of the puzzle input and compares them to the accepted
+/// answers, or returns `None` if the puzzle input is missing.
+pub fn verify(entry: &Entry, parts: &[Part]) -> Result<Option<Vec<Outcome>>> {
+    if !input::default_path(entry.day).exists() {
+        return Ok(None);
+    }
+
+    let input = input::load(entry.day, None)?;
+    let answers = Answers::load(entry.day)?;
+    let actual = entry.solve(&input, parts)?;
+
+    let outcomes = parts
+        .iter()
+        .zip(actual)
+        .map(|(&part, actual)| Outcome {
+            part,
+            expected: answers.get(part).map(String::from),
+            actual,
+        })
+        .collect();
+
+    Ok(Some(outcomes))
+}
+
+/// Records `answers` as accepted for `day`, keeping the other part as is.
+pub fn accept(day: u8, answers: impl IntoIterator<Item = (Part, String)>) -> Result<()> {
+    let mut accepted = Answers::load(day)?;
+
+    for (part, answer) in answers {
+        accepted.set(part, answer);
+    }
+
+    accepted.save(day)
+}
+
+/// Test helper asserting that the puzzle input still gives the accepted
+/// answers, skipped when the puzzle input or the answers are missing.
+pub fn assert_accepted(entry: &Entry) -> Result<()> {
+    let answers = Answers::load(entry.day)?;
+    let parts: Vec<Part> = Part::BOTH
+        .into_iter()
+        .filter(|&part| answers.get(part).is_some())
+        .collect();
+
+    if parts.is_empty() {
+        eprintln!("skipped: no accepted answers for day {:02}", entry.day);
+        return Ok(());
+    }
+
+    let Some(outcomes) = verify(entry, &parts)? else {
+        eprintln!("skipped: missing puzzle input for day {:02}", entry.day);
+        return Ok(());
+    };
+
+    for outcome in outcomes {
+        assert_eq!(
+            outcome.expected.as_deref(),
+            Some(outcome.actual.as_str()),
+            "day {:02}, {}",
+            entry.day,
+            outcome.part,
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_answers_toml() -> Result<()> {
+    let toml = "\
+# Accepted answers of day 5
+part_1 = \"3\"
+part_2 = \"14\"
+";
+
+    let answers = Answers::from_toml(toml)?;
+    assert_eq!(answers.get(Part::One), Some("3"));
+    assert_eq!(answers.get(Part::Two), Some("14"));
+    assert_eq!(answers.to_toml(5), toml);
+
+    assert_eq!(Answers::from_toml("part_2 = \"1\"")?.get(Part::One), None);
+    assert!(Answers::from_toml("part_3 = \"1\"").is_err());
+    assert!(Answers::from_toml("part_1 = 1").is_err());
+
+    // quotes and backslashes are escaped, so the file stays valid
+    let mut answers = Answers::default();
+    answers.set(Part::One, r#"say "hi" \o/"#.to_string());
+    answers.set(Part::Two, "two\nlines".to_string());
+    let toml = answers.to_toml(5);
+    assert!(toml.contains(r#"part_1 = "say \"hi\" \\o/""#));
+    assert!(toml.contains(r#"part_2 = "two\nlines""#));
+    assert_eq!(Answers::from_toml(&toml)?, answers);
+
+    assert!(Answers::from_toml(r#"part_1 = "a"b""#).is_err());
+    assert!(Answers::from_toml(r#"part_1 = "a\tb""#).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,593 @@
+use anyhow::{Context, Result, bail};
+use aoc_for_rustaceans::{
+    animation,
+    answers::{self, Outcome},
+    days::{self, CALENDAR},
+    input,
+    runner::{Entry, ExplainFormat, Part},
+    timing::{self, Row},
+};
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--input <path>] [--accept] [--explain [--json]]
+       aoc verify [day|all] [--part <1|2>]
+       aoc explain <day> [--part <1|2>] [--input <path>] [--json] [--pgm <path>]
+       aoc time <day|all> [--part <1|2>] [--input <path>] [--repeat <n>] [--markdown]
+       aoc animate <day> [--input <path>] [--delay <ms>] [--gif <path> | --svg <dir>]";
+
+const DEFAULT_REPEAT: usize = 10;
+
+const DEFAULT_DELAY: Duration = Duration::from_millis(200);
+
+/// Pixels per cell of the exported animations.
+const CELL_SIZE: usize = 4;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        options: Options,
+        accept: bool,
+        explain: Option<Explain>,
+    },
+    Verify(Options),
+    Explain {
+        options: Options,
+        explain: Explain,
+        pgm: Option<PathBuf>,
+    },
+    Time {
+        options: Options,
+        repeat: usize,
+        markdown: bool,
+    },
+    Animate {
+        options: Options,
+        delay: Duration,
+        export: Option<Export>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Explain {
+    Text,
+    Json,
+}
+
+/// Where `animate` writes its frames instead of playing them.
+#[derive(Debug, PartialEq)]
+enum Export {
+    Gif(PathBuf),
+    Svg(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    days: Days,
+    parts: Vec<Part>,
+    input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Days {
+    One(u8),
+    All,
+}
+
+impl Options {
+    fn entries(&self) -> Result<Vec<&'static Entry>> {
+        match self.days {
+            Days::One(day) => Ok(vec![
+                days::get(day).with_context(|| format!("day {day} is not solved"))?,
+            ]),
+            Days::All => Ok(CALENDAR.iter().collect()),
+        }
+    }
+}
+
+impl Command {
+    fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+
+        let command = args.next().with_context(|| USAGE.to_string())?;
+        if !matches!(
+            command.as_str(),
+            "run" | "verify" | "explain" | "time" | "animate"
+        ) {
+            bail!("unknown command `{command}`\n\n{USAGE}");
+        }
+
+        let mut days = None;
+        let mut parts = Part::BOTH.to_vec();
+        let mut input = None;
+        let mut repeat = None;
+        let mut markdown = false;
+        let mut accept = false;
+        let mut explain = false;
+        let mut json = false;
+        let mut part = false;
+        let mut delay = None;
+        let mut export = None;
+        let mut pgm = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().context("missing value for `--part`")?;
+                    parts = vec![value.parse()?];
+                    part = true;
+                }
+                "--input" => {
+                    let path = args.next().context("missing value for `--input`")?;
+                    input = Some(PathBuf::from(path));
+                }
+                "--repeat" if command == "time" => {
+                    let n = args.next().context("missing value for `--repeat`")?;
+                    let n = n
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .with_context(|| format!("invalid repeat count `{n}`"))?;
+                    repeat = Some(n);
+                }
+                "--markdown" if command == "time" => markdown = true,
+                "--accept" if command == "run" => accept = true,
+                "--explain" if command == "run" => explain = true,
+                "--json" if command == "run" || command == "explain" => json = true,
+                "--pgm" if command == "explain" => {
+                    let path = args.next().context("missing value for `--pgm`")?;
+                    pgm = Some(PathBuf::from(path));
+                }
+                "--delay" if command == "animate" => {
+                    let ms = args.next().context("missing value for `--delay`")?;
+                    let ms = ms
+                        .parse()
+                        .with_context(|| format!("invalid delay `{ms}`"))?;
+                    delay = Some(Duration::from_millis(ms));
+                }
+                "--gif" | "--svg" if command == "animate" => {
+                    if export.is_some() {
+                        bail!("`--gif` and `--svg` can't be used together");
+                    }
+                    let path = args
+                        .next()
+                        .with_context(|| format!("missing value for `{arg}`"))?;
+                    let path = PathBuf::from(path);
+                    export = Some(if arg == "--gif" {
+                        Export::Gif(path)
+                    } else {
+                        Export::Svg(path)
+                    });
+                }
+                "all" if days.is_none() => days = Some(Days::All),
+                day if days.is_none() && !day.starts_with('-') => {
+                    let day = day
+                        .parse()
+                        .with_context(|| format!("invalid day `{day}`\n\n{USAGE}"))?;
+                    days = Some(Days::One(day));
+                }
+                _ => bail!("unexpected argument `{arg}`\n\n{USAGE}"),
+            }
+        }
+
+        let days = match days {
+            Some(days) => days,
+            None if command == "verify" => Days::All,
+            None => bail!("missing day\n\n{USAGE}"),
+        };
+
+        if days == Days::All && input.is_some() {
+            bail!("`--input` can only be used when running a single day");
+        }
+        if command == "verify" && input.is_some() {
+            bail!("`verify` always uses the examples and the puzzle input");
+        }
+        if accept && (days == Days::All || input.is_some()) {
+            bail!("`--accept` records the answers of a single day to its puzzle input");
+        }
+        let explain = explain || command == "explain";
+        if explain && days == Days::All {
+            bail!("only a single day can be explained");
+        }
+        if command == "animate" && days == Days::All {
+            bail!("`animate` can only be used on a single day");
+        }
+        if command == "animate" && part {
+            bail!("`animate` shows the whole puzzle, not a part");
+        }
+        if json && !explain {
+            bail!("`--json` only applies to `--explain`");
+        }
+
+        let explain = explain.then_some(if json { Explain::Json } else { Explain::Text });
+
+        let options = Options { days, parts, input };
+
+        match command.as_str() {
+            "run" => Ok(Command::Run {
+                options,
+                accept,
+                explain,
+            }),
+            "verify" => Ok(Command::Verify(options)),
+            "explain" => Ok(Command::Explain {
+                options,
+                explain: explain.unwrap_or(Explain::Text),
+                pgm,
+            }),
+            "animate" => Ok(Command::Animate {
+                options,
+                delay: delay.unwrap_or(DEFAULT_DELAY),
+                export,
+            }),
+            _ => Ok(Command::Time {
+                options,
+                repeat: repeat.unwrap_or(DEFAULT_REPEAT),
+                markdown,
+            }),
+        }
+    }
+}
+
+fn load(entry: &Entry, options: &Options) -> Result<String> {
+    input::load(entry.day, options.input.as_deref())
+}
+
+fn run(options: &Options, accept: bool, explain: Option<Explain>) -> Result<()> {
+    for (i, entry) in options.entries()?.into_iter().enumerate() {
+        if options.days == Days::All {
+            if i > 0 {
+                println!();
+            }
+            println!("Day {:02}", entry.day);
+        }
+
+        let input = load(entry, options)?;
+        let answers = entry
+            .solve(&input, &options.parts)
+            .with_context(|| format!("failed to solve day {:02}", entry.day))?;
+
+        match explain {
+            // only the JSON, so it can be piped to other tools
+            Some(Explain::Json) => {
+                print_explanations(entry, &input, &options.parts, Explain::Json)?
+            }
+            _ => {
+                for (part, answer) in options.parts.iter().zip(&answers) {
+                    println!("{part}: {answer}");
+                }
+            }
+        }
+
+        if explain == Some(Explain::Text) {
+            println!();
+            print_explanations(entry, &input, &options.parts, Explain::Text)?;
+        }
+
+        if accept {
+            answers::accept(entry.day, options.parts.iter().copied().zip(answers))?;
+            println!("Accepted answers of day {:02}", entry.day);
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the explanation of each of the `parts`, the JSON ones a line each.
+fn print_explanations(entry: &Entry, input: &str, parts: &[Part], explain: Explain) -> Result<()> {
+    for (i, &part) in parts.iter().enumerate() {
+        match explain {
+            Explain::Json => println!("{}", explanation(entry, input, part, ExplainFormat::Json)?),
+            Explain::Text => {
+                let color = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+                let explanation = explanation(entry, input, part, ExplainFormat::Text { color })?;
+
+                if i > 0 {
+                    println!();
+                }
+                println!("{part}, explained:\n{explanation}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn explanation(entry: &Entry, input: &str, part: Part, format: ExplainFormat) -> Result<String> {
+    entry
+        .explain(input, part, format)
+        .with_context(|| format!("failed to explain day {:02}", entry.day))?
+        .with_context(|| format!("day {:02} can't explain its answers", entry.day))
+}
+
+fn verify(options: &Options) -> Result<()> {
+    let mut wrong = 0;
+    let mut report = |day: u8, label: &str, outcome: Outcome| {
+        let status = match &outcome.expected {
+            None => format!("{} (not accepted yet)", outcome.actual),
+            Some(expected) if outcome.is_correct() => format!("{expected} (ok)"),
+            Some(expected) => {
+                wrong += 1;
+                format!("{} (WRONG, expected {expected})", outcome.actual)
+            }
+        };
+
+        println!("Day {day:02}, {}{label}: {status}", outcome.part);
+    };
+
+    for entry in options.entries()? {
+        let examples = answers::verify_examples(entry, &options.parts)
+            .with_context(|| format!("failed to solve the examples of day {:02}", entry.day))?;
+        for outcome in examples {
+            report(entry.day, " example", outcome);
+        }
+
+        let outcomes = answers::verify(entry, &options.parts)
+            .with_context(|| format!("failed to solve day {:02}", entry.day))?;
+
+        let Some(outcomes) = outcomes else {
+            println!("Day {:02}: skipped, missing puzzle input", entry.day);
+            continue;
+        };
+
+        for outcome in outcomes {
+            report(entry.day, "", outcome);
+        }
+    }
+
+    if wrong > 0 {
+        bail!("{wrong} answer(s) differ from the expected ones");
+    }
+
+    Ok(())
+}
+
+fn explain(options: &Options, explain: Explain, pgm: Option<&Path>) -> Result<()> {
+    for entry in options.entries()? {
+        let input = load(entry, options)?;
+        print_explanations(entry, &input, &options.parts, explain)?;
+
+        if let Some(path) = pgm {
+            let image = entry
+                .pgm(&input)
+                .with_context(|| format!("failed to draw day {:02}", entry.day))?
+                .with_context(|| format!("day {:02} can't draw a PGM image", entry.day))?;
+
+            fs::write(path, image)
+                .with_context(|| format!("failed to write `{}`", path.display()))?;
+            // on stderr, to keep the JSON alone on stdout
+            eprintln!("Wrote {}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn time(options: &Options, repeat: usize, markdown: bool) -> Result<()> {
+    let mut rows = Vec::new();
+
+    for entry in options.entries()? {
+        let measurement = entry
+            .measure(&load(entry, options)?, &options.parts, repeat)
+            .with_context(|| format!("failed to solve day {:02}", entry.day))?;
+
+        rows.push(Row {
+            day: entry.day,
+            step: "Parse".to_string(),
+            answer: String::new(),
+            timing: measurement.parse,
+        });
+
+        for (part, answer, timing) in measurement.parts {
+            rows.push(Row {
+                day: entry.day,
+                step: part.to_string(),
+                answer,
+                timing,
+            });
+        }
+    }
+
+    println!("{}", timing::table(&rows, markdown));
+
+    Ok(())
+}
+
+fn animate(options: &Options, delay: Duration, export: Option<&Export>) -> Result<()> {
+    for entry in options.entries()? {
+        let frames = entry
+            .animate(&load(entry, options)?)
+            .with_context(|| format!("failed to animate day {:02}", entry.day))?
+            .with_context(|| format!("day {:02} has nothing to animate", entry.day))?;
+
+        match export {
+            None => animation::play(&frames, delay, &mut io::stdout().lock())?,
+            Some(Export::Gif(path)) => {
+                let gif = animation::gif(&frames, CELL_SIZE, delay)?;
+                fs::write(path, gif)
+                    .with_context(|| format!("failed to write `{}`", path.display()))?;
+                println!("Wrote {} frames to {}", frames.len(), path.display());
+            }
+            Some(Export::Svg(dir)) => {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("failed to create `{}`", dir.display()))?;
+
+                for (i, frame) in frames.iter().enumerate() {
+                    let path = dir.join(format!("frame_{i:03}.svg"));
+                    fs::write(&path, animation::svg(frame, CELL_SIZE))
+                        .with_context(|| format!("failed to write `{}`", path.display()))?;
+                }
+                println!("Wrote {} frames to {}", frames.len(), dir.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Command::from_args(env::args().skip(1))? {
+        Command::Run {
+            options,
+            accept,
+            explain,
+        } => run(&options, accept, explain),
+        Command::Verify(options) => verify(&options),
+        Command::Explain {
+            options,
+            explain: format,
+            pgm,
+        } => explain(&options, format, pgm.as_deref()),
+        Command::Time {
+            options,
+            repeat,
+            markdown,
+        } => time(&options, repeat, markdown),
+        Command::Animate {
+            options,
+            delay,
+            export,
+        } => animate(&options, delay, export.as_ref()),
+    }
+}
+
+#[test]
+fn test_from_args() -> Result<()> {
+    let args = |args: &str| {
+        args.split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        Command::from_args(args("run 5"))?,
+        Command::Run {
+            options: Options {
+                days: Days::One(5),
+                parts: vec![Part::One, Part::Two],
+                input: None,
+            },
+            accept: false,
+            explain: None,
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("run --part 2 all"))?,
+        Command::Run {
+            options: Options {
+                days: Days::All,
+                parts: vec![Part::Two],
+                input: None,
+            },
+            accept: false,
+            explain: None,
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("run 05 --input -"))?,
+        Command::Run {
+            options: Options {
+                days: Days::One(5),
+                parts: vec![Part::One, Part::Two],
+                input: Some(PathBuf::from("-")),
+            },
+            accept: false,
+            explain: None,
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("run 5 --part 1 --accept"))?,
+        Command::Run {
+            options: Options {
+                days: Days::One(5),
+                parts: vec![Part::One],
+                input: None,
+            },
+            accept: true,
+            explain: None,
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("run 3 --explain --json"))?,
+        Command::Run {
+            options: Options {
+                days: Days::One(3),
+                parts: vec![Part::One, Part::Two],
+                input: None,
+            },
+            accept: false,
+            explain: Some(Explain::Json),
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("verify"))?,
+        Command::Verify(Options {
+            days: Days::All,
+            parts: vec![Part::One, Part::Two],
+            input: None,
+        })
+    );
+    assert_eq!(
+        Command::from_args(args("time all --repeat 3 --markdown"))?,
+        Command::Time {
+            options: Options {
+                days: Days::All,
+                parts: vec![Part::One, Part::Two],
+                input: None,
+            },
+            repeat: 3,
+            markdown: true,
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("explain 4 --part 1 --pgm day04.pgm"))?,
+        Command::Explain {
+            options: Options {
+                days: Days::One(4),
+                parts: vec![Part::One],
+                input: None,
+            },
+            explain: Explain::Text,
+            pgm: Some(PathBuf::from("day04.pgm")),
+        }
+    );
+    assert_eq!(
+        Command::from_args(args("animate 4 --delay 50 --gif day04.gif"))?,
+        Command::Animate {
+            options: Options {
+                days: Days::One(4),
+                parts: vec![Part::One, Part::Two],
+                input: None,
+            },
+            delay: Duration::from_millis(50),
+            export: Some(Export::Gif(PathBuf::from("day04.gif"))),
+        }
+    );
+
+    assert!(Command::from_args(args("")).is_err());
+    assert!(Command::from_args(args("run")).is_err());
+    assert!(Command::from_args(args("run 5 6")).is_err());
+    assert!(Command::from_args(args("run 5 --part 3")).is_err());
+    assert!(Command::from_args(args("run 5 --repeat 3")).is_err());
+    assert!(Command::from_args(args("time 5 --repeat 0")).is_err());
+    assert!(Command::from_args(args("run all --input -")).is_err());
+    assert!(Command::from_args(args("run all --accept")).is_err());
+    assert!(Command::from_args(args("run 5 --input - --accept")).is_err());
+    assert!(Command::from_args(args("verify 5 --input -")).is_err());
+    assert!(Command::from_args(args("run all --explain")).is_err());
+    assert!(Command::from_args(args("run 3 --json")).is_err());
+    assert!(Command::from_args(args("time 3 --explain")).is_err());
+    assert!(Command::from_args(args("explain all")).is_err());
+    assert!(Command::from_args(args("run 4 --pgm day04.pgm")).is_err());
+    assert!(Command::from_args(args("animate all")).is_err());
+    assert!(Command::from_args(args("animate 4 --part 2")).is_err());
+    assert!(Command::from_args(args("animate 4 --gif a.gif --svg frames")).is_err());
+    assert!(Command::from_args(args("run 4 --delay 50")).is_err());
+
+    Ok(())
+}
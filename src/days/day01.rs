@@ -0,0 +1,118 @@
+use crate::{
+    Solution,
+    parse::{self, ParseError},
+};
+use anyhow::Result;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "3";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "6";
+
+    type Input<'a> = Vec<(char, i32)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let direction = match l.chars().next() {
+                    Some(direction @ ('L' | 'R')) => direction,
+                    _ => return Err(ParseError::at(Self::DAY, input, l, "`L` or `R`").into()),
+                };
+                let distance = parse::number(Self::DAY, input, &l[1..], "a distance")?;
+                Ok((direction, distance))
+            })
+            .collect()
+    }
+
+    fn part_one(rotations: &Self::Input<'_>) -> Result<i32> {
+        let mut dial = 50;
+
+        let pointings: Vec<i32> = rotations
+            .iter()
+            .map(|(direction, distance)| {
+                if *direction == 'L' {
+                    dial += 100 - (distance % 100);
+                } else if *direction == 'R' {
+                    dial += distance;
+                }
+
+                dial % 100
+            })
+            .collect();
+
+        let at_zero_count = pointings.iter().filter(|i| **i == 0).count() as i32;
+
+        Ok(at_zero_count)
+    }
+
+    fn part_two(rotations: &Self::Input<'_>) -> Result<i32> {
+        let mut dial = 50;
+        let mut zero_while_rotation = 0;
+
+        let pointings: Vec<i32> = rotations
+            .iter()
+            .map(|(direction, distance)| {
+                zero_while_rotation += count_rotations(*distance);
+                let dist_rem = distance % 100;
+
+                if *direction == 'L' {
+                    if dial != 0 && dial - dist_rem < 0 {
+                        zero_while_rotation += 1;
+                    }
+                    dial += 100 - dist_rem;
+                } else if *direction == 'R' {
+                    if dial + dist_rem > 100 {
+                        zero_while_rotation += 1;
+                    }
+                    dial += dist_rem;
+                }
+
+                dial %= 100;
+                dial
+            })
+            .collect();
+
+        let at_zero_count = pointings.iter().filter(|i| **i == 0).count() as i32;
+
+        Ok(at_zero_count + zero_while_rotation)
+    }
+}
+
+fn count_rotations(dist: i32) -> i32 {
+    if dist > 100 { dist / 100 } else { 0 }
+}
+
+crate::solution!(Day01);
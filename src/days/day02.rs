@@ -0,0 +1,345 @@
+use crate::{Solution, digits, parse};
+use anyhow::{Result, ensure};
+use num_bigint::BigUint;
+use std::ops::RangeInclusive;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "1227775554";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "4174379265";
+
+    type Input<'a> = Vec<RangeInclusive<u64>>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let ranges = input
+            .trim()
+            .split(',')
+            .map(|r| parse::range(Self::DAY, input, r))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ranges)
+    }
+
+    fn part_one(ranges: &Self::Input<'_>) -> Result<u128> {
+        ranges
+            .iter()
+            .map(|r| Ok(invalid_ids(widen(r), InvalidIdRule::PART_ONE)?.sum::<u128>()))
+            .sum()
+    }
+
+    fn part_two(ranges: &Self::Input<'_>) -> Result<u128> {
+        ranges
+            .iter()
+            .map(|r| Ok(invalid_ids(widen(r), InvalidIdRule::PART_TWO)?.sum::<u128>()))
+            .sum()
+    }
+}
+
+fn widen(range: &RangeInclusive<u64>) -> RangeInclusive<u128> {
+    (*range.start()).into()..=(*range.end()).into()
+}
+
+/// What makes an ID invalid: its digits in `base` are a pattern repeated
+/// between `min_repeats` and `max_repeats` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidIdRule {
+    base: u32,
+    min_repeats: u32,
+    max_repeats: u32,
+}
+
+impl InvalidIdRule {
+    /// A pattern repeated exactly twice, e.g. `6464`.
+    pub const PART_ONE: Self = Self {
+        base: 10,
+        min_repeats: 2,
+        max_repeats: 2,
+    };
+
+    /// A pattern repeated twice or more, e.g. `6464` or `646464`.
+    pub const PART_TWO: Self = Self {
+        base: 10,
+        min_repeats: 2,
+        max_repeats: u32::MAX,
+    };
+
+    pub fn new(base: u32, min_repeats: u32, max_repeats: u32) -> Result<Self> {
+        ensure!(base >= 2, "expected a base of at least 2, found {base}");
+        ensure!(
+            min_repeats >= 2,
+            "expected at least 2 repeats, found {min_repeats}"
+        );
+        ensure!(
+            min_repeats <= max_repeats,
+            "expected at most {max_repeats} repeats to allow {min_repeats}"
+        );
+
+        Ok(Self {
+            base,
+            min_repeats,
+            max_repeats,
+        })
+    }
+
+    /// Whether `id` is invalid, checking its digits one by one.
+    pub fn is_invalid(&self, id: u128) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = id;
+        loop {
+            digits.push(rest % u128::from(self.base));
+            rest /= u128::from(self.base);
+            if rest == 0 {
+                break;
+            }
+        }
+
+        let length = digits.len() as u32;
+        (self.min_repeats..=self.max_repeats.min(length))
+            .filter(|&r| length.is_multiple_of(r))
+            .any(|r| {
+                let pattern = &digits[..(length / r) as usize];
+                digits.chunks(pattern.len()).all(|chunk| chunk == pattern)
+            })
+    }
+
+    /// The repeat counts the invalid IDs of `length` digits are built from.
+    ///
+    /// An ID repeating its pattern `r` times also repeats a longer pattern `d`
+    /// times for any divisor `d` of `r`, so counts that another one divides
+    /// are left out.
+    fn repeats(&self, length: u32) -> Vec<u32> {
+        let counts: Vec<u32> = (self.min_repeats..=self.max_repeats.min(length))
+            .filter(|&r| length.is_multiple_of(r))
+            .collect();
+
+        counts
+            .iter()
+            .copied()
+            .filter(|&r| !counts.iter().any(|&d| d < r && r.is_multiple_of(d)))
+            .collect()
+    }
+
+    /// The multiplier turning a pattern into an ID of `length` digits
+    /// repeating it `repeats` times, e.g. `10101` for a length of 6 and 3
+    /// repeats in base 10.
+    fn multiplier(&self, length: u32, repeats: u32) -> u128 {
+        let pattern_length = length / repeats;
+        (0..repeats)
+            .map(|i| u128::from(self.base).pow(pattern_length * i))
+            .sum()
+    }
+}
+
+/// The invalid IDs in `range`, built from their pattern instead of checking
+/// every ID, in no particular order.
+///
+/// An ID of `length` digits repeating a pattern `repeats` times is the pattern
+/// times a multiplier like `1001` (3 digits, twice) or `10101` (2 digits,
+/// three times), so they are the multiples of the multiplier in the range. IDs
+/// that repeat several ways, like `111111`, are only built from the first
+/// repeat count matching them.
+pub fn invalid_ids(
+    range: RangeInclusive<u128>,
+    rule: InvalidIdRule,
+) -> Result<impl Iterator<Item = u128>> {
+    let ranges = digits::split_by_digit_count_in_base(range, rule.base)?;
+
+    Ok(ranges.flat_map(move |ids| {
+        let length = digits::digit_count(*ids.start(), rule.base);
+        let multipliers: Vec<u128> = rule
+            .repeats(length)
+            .into_iter()
+            .map(|repeats| rule.multiplier(length, repeats))
+            .collect();
+
+        (0..multipliers.len()).flat_map(move |i| {
+            let multiplier = multipliers[i];
+            let earlier = multipliers[..i].to_vec();
+
+            (ids.start().div_ceil(multiplier)..=ids.end() / multiplier)
+                .map(move |pattern| pattern * multiplier)
+                .filter(move |id| earlier.iter().all(|m| !id.is_multiple_of(*m)))
+        })
+    }))
+}
+
+/// How many invalid IDs a range holds, and their sum.
+#[derive(Debug, Default, PartialEq)]
+pub struct Totals {
+    pub count: u128,
+    pub sum: BigUint,
+}
+
+/// Counts and sums the invalid IDs in `range` without building them, so even
+/// `0..=u128::MAX` is instant.
+///
+/// The multiples of a multiplier are an arithmetic series. IDs repeating
+/// several ways are handled by inclusion–exclusion, as the IDs repeating both
+/// `a` and `b` times are the ones repeating `lcm(a, b)` times.
+pub fn invalid_id_totals(range: RangeInclusive<u128>, rule: InvalidIdRule) -> Result<Totals> {
+    let mut included = Totals::default();
+    let mut excluded = Totals::default();
+
+    for ids in digits::split_by_digit_count_in_base(range, rule.base)? {
+        let length = digits::digit_count(*ids.start(), rule.base);
+        let repeats = rule.repeats(length);
+
+        for subset in 1..1u64 << repeats.len() {
+            let lcm = (0..repeats.len())
+                .filter(|i| subset >> i & 1 == 1)
+                .fold(1, |lcm, i| lcm / gcd(lcm, repeats[i]) * repeats[i]);
+
+            let totals = if subset.count_ones() % 2 == 1 {
+                &mut included
+            } else {
+                &mut excluded
+            };
+
+            let multiplier = rule.multiplier(length, lcm);
+            let first = ids.start().div_ceil(multiplier);
+            let last = ids.end() / multiplier;
+            if first > last {
+                continue;
+            }
+
+            // halve whichever factor is even, so nothing overflows
+            let count = last - first + 1;
+            let patterns = if count.is_multiple_of(2) {
+                count / 2 * (first + last)
+            } else {
+                (first + last) / 2 * count
+            };
+
+            totals.count += count;
+            totals.sum += BigUint::from(patterns) * multiplier;
+        }
+    }
+
+    Ok(Totals {
+        count: included.count - excluded.count,
+        sum: included.sum - excluded.sum,
+    })
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[test]
+fn test_invalid_id_rule() -> Result<()> {
+    assert!(InvalidIdRule::PART_ONE.is_invalid(6464));
+    assert!(!InvalidIdRule::PART_ONE.is_invalid(646464));
+    assert!(InvalidIdRule::PART_TWO.is_invalid(646464));
+    assert!(!InvalidIdRule::PART_TWO.is_invalid(6));
+
+    let hexadecimal = InvalidIdRule::new(16, 2, 2)?;
+    assert!(hexadecimal.is_invalid(0xabab));
+    assert!(!hexadecimal.is_invalid(4545));
+
+    assert!(InvalidIdRule::new(1, 2, 2).is_err());
+    assert!(InvalidIdRule::new(10, 1, 2).is_err());
+    assert!(InvalidIdRule::new(10, 3, 2).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_ids() -> Result<()> {
+    let rules = [
+        InvalidIdRule::PART_ONE,
+        InvalidIdRule::PART_TWO,
+        InvalidIdRule::new(10, 3, 3)?,
+        InvalidIdRule::new(10, 3, u32::MAX)?,
+        InvalidIdRule::new(16, 2, 2)?,
+        InvalidIdRule::new(2, 2, 4)?,
+    ];
+    let ranges = [
+        1..=10_000,
+        95..=115,
+        998..=1012,
+        99_990..=1_000_020,
+        123_000..=124_500,
+        222_220..=222_224,
+    ];
+
+    for rule in rules {
+        for range in ranges.clone() {
+            let mut ids: Vec<u128> = invalid_ids(range.clone(), rule)?.collect();
+            ids.sort();
+
+            let expected: Vec<u128> = range.clone().filter(|&id| rule.is_invalid(id)).collect();
+            assert_eq!(ids, expected, "{range:?}, {rule:?}");
+        }
+    }
+
+    // far too wide to check every ID, but with few invalid ones
+    let mut ids: Vec<u128> = invalid_ids(
+        10u128.pow(19)..=10u128.pow(19) + 10u128.pow(12),
+        InvalidIdRule::PART_TWO,
+    )?
+    .collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 100);
+    assert!(ids.contains(&10_000_000_001_000_000_000));
+    assert!(ids.iter().all(|&id| InvalidIdRule::PART_TWO.is_invalid(id)));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_id_totals() -> Result<()> {
+    let ranges = Day02::parse(Day02::EXAMPLE_INPUT_1)?;
+    let rules = [
+        InvalidIdRule::PART_ONE,
+        InvalidIdRule::PART_TWO,
+        InvalidIdRule::new(10, 3, 6)?,
+        InvalidIdRule::new(16, 2, u32::MAX)?,
+        InvalidIdRule::new(2, 3, u32::MAX)?,
+    ];
+
+    for range in ranges
+        .iter()
+        .map(widen)
+        .chain([0..=100_000, 998_877..=1_234_567])
+    {
+        for rule in rules {
+            let ids: Vec<u128> = invalid_ids(range.clone(), rule)?.collect();
+            let expected = Totals {
+                count: ids.len() as u128,
+                sum: ids.iter().sum::<u128>().into(),
+            };
+            assert_eq!(invalid_id_totals(range.clone(), rule)?, expected);
+        }
+    }
+
+    // every pattern of 1 to 19 digits repeated twice
+    let totals = invalid_id_totals(0..=u128::MAX, InvalidIdRule::PART_ONE)?;
+    assert_eq!(totals.count, 10u128.pow(19) - 1);
+
+    // the first ones to repeat three times, 111 to 999, and then 1010 to 9999
+    let totals = invalid_id_totals(0..=9999, InvalidIdRule::PART_TWO)?;
+    assert_eq!(totals.count, 9 + 9 + 90);
+
+    // every pattern of 1 to 64 bits repeated twice
+    let totals = invalid_id_totals(0..=u128::MAX, InvalidIdRule::new(2, 2, 2)?)?;
+    assert_eq!(totals.count, u128::from(u64::MAX));
+
+    Ok(())
+}
+
+crate::solution!(Day02);
@@ -0,0 +1,461 @@
+use crate::{
+    Solution,
+    parse::ParseError,
+    runner::{ExplainFormat, Part},
+};
+use anyhow::{Context, Result, bail, ensure};
+use num_bigint::BigUint;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "357";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "3121910778619";
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let banks: Vec<&str> = input.lines().collect();
+
+        for bank in &banks {
+            if let Some(i) = bank.find(|c: char| !c.is_ascii_digit()) {
+                let expected = "a battery joltage from 0 to 9";
+                return Err(ParseError::at(Self::DAY, input, &bank[i..], expected).into());
+            }
+        }
+
+        Ok(banks)
+    }
+
+    fn part_one(banks: &Self::Input<'_>) -> Result<u64> {
+        total_joltage(banks, batteries(Part::One))
+    }
+
+    fn part_two(banks: &Self::Input<'_>) -> Result<u64> {
+        total_joltage(banks, batteries(Part::Two))
+    }
+
+    fn explain(
+        banks: &Self::Input<'_>,
+        part: Part,
+        format: ExplainFormat,
+    ) -> Result<Option<String>> {
+        let k = batteries(part);
+        let selections = banks
+            .iter()
+            .map(|bank| max_joltage_k(bank, k))
+            .collect::<Result<Vec<_>>>()?;
+
+        let explanation = match format {
+            ExplainFormat::Text { color } => explain_text(banks, &selections, color),
+            ExplainFormat::Json => explain_json(part, k, banks, &selections),
+        };
+
+        Ok(Some(explanation))
+    }
+}
+
+/// How many batteries each bank switches on.
+fn batteries(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 12,
+    }
+}
+
+/// The puzzle switches on at most 12 batteries per bank, so their joltages fit
+/// in a `u64`, unlike those of [`select`] in general.
+fn total_joltage(banks: &[&str], k: usize) -> Result<u64> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(i, bank)| {
+            let selection = max_joltage_k(bank, k).with_context(|| format!("bank {}", i + 1))?;
+            u64::try_from(&selection.joltage)
+                .with_context(|| format!("the joltage of bank {} doesn't fit in a `u64`", i + 1))
+        })
+        .sum()
+}
+
+/// The batteries switched on in a bank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Positions of the batteries in the bank, in increasing order.
+    pub indices: Vec<usize>,
+    /// Their joltages read as a single number.
+    pub joltage: BigUint,
+}
+
+impl Selection {
+    fn new(joltages: &[u8], indices: Vec<usize>) -> Self {
+        let joltage = indices.iter().fold(BigUint::ZERO, |joltage, &i| {
+            joltage * 10u8 + (joltages[i] - b'0')
+        });
+
+        Self { indices, joltage }
+    }
+}
+
+/// Which `k` batteries of a bank to switch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The largest joltage, as in the puzzle.
+    Largest,
+    /// The smallest joltage.
+    Smallest,
+    /// The largest joltage leaving at most this many batteries off in a row,
+    /// before, between and after the ones switched on.
+    MaxSkipsInARow(usize),
+    /// The largest joltage switching on at least one battery of this joltage.
+    RequiredDigit(u8),
+}
+
+/// Switches on the `k` batteries of `bank` giving the largest joltage.
+pub fn max_joltage_k(bank: &str, k: usize) -> Result<Selection> {
+    select(bank, k, Mode::Largest)
+}
+
+/// Switches on `k` batteries of `bank` as `mode` asks.
+pub fn select(bank: &str, k: usize, mode: Mode) -> Result<Selection> {
+    if let Some((at, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        bail!("expected a battery joltage from 0 to 9, found `{c}` at {at}");
+    }
+
+    let joltages = bank.as_bytes();
+    ensure!(
+        joltages.len() >= k,
+        "can't switch on {k} batteries in a bank of {}",
+        joltages.len()
+    );
+
+    let indices = match mode {
+        Mode::Largest => stack_select(joltages, k, |last, next| last < next),
+        Mode::Smallest => stack_select(joltages, k, |last, next| last > next),
+        Mode::MaxSkipsInARow(max_skips) => select_with_max_skips(joltages, k, max_skips)
+            .with_context(|| {
+                format!("can't switch on {k} batteries without skipping more than {max_skips}")
+            })?,
+        Mode::RequiredDigit(digit) => {
+            ensure!(digit <= 9, "expected a joltage from 0 to 9, found {digit}");
+            select_with_digit(joltages, k, b'0' + digit)
+                .with_context(|| format!("can't switch on {k} batteries including a {digit}"))?
+        }
+    };
+
+    Ok(Selection::new(joltages, indices))
+}
+
+/// Keeps a stack of the batteries switched on so far, and replaces the last
+/// one whenever the next one should `replace` it, as long as enough batteries
+/// are left to switch `k` of them on.
+fn stack_select(joltages: &[u8], k: usize, replace: fn(u8, u8) -> bool) -> Vec<usize> {
+    let mut indices: Vec<usize> = Vec::with_capacity(joltages.len());
+    let mut remaining_drops = joltages.len() - k;
+
+    for (i, &joltage) in joltages.iter().enumerate() {
+        while remaining_drops > 0
+            && let Some(&last) = indices.last()
+            && replace(joltages[last], joltage)
+        {
+            indices.pop();
+            remaining_drops -= 1;
+        }
+
+        indices.push(i);
+    }
+
+    indices.truncate(k);
+    indices
+}
+
+/// Picks the batteries one at a time, like the stack, but a stack can't be
+/// used: a run of batteries off can only be kept short by switching on a
+/// battery the stack would have dropped, e.g. `9875` with 2 batteries and at
+/// most 1 off in a row must give `97`, which a stack never pops back to.
+///
+/// Instead, each step keeps every position where the best batteries so far
+/// can end, tied as they have the same joltages, and picks the largest
+/// battery reachable from any of them that still leaves a way to finish.
+fn select_with_max_skips(joltages: &[u8], k: usize, max_skips: usize) -> Option<Vec<usize>> {
+    let n = joltages.len();
+    // whether `left` batteries can be switched on among the `rest` following
+    // one, never leaving more than `max_skips` off in a row
+    let can_finish =
+        |rest: usize, left: usize| left <= rest && rest - left <= (left + 1) * max_skips;
+
+    if !can_finish(n, k) {
+        return None;
+    }
+
+    // the positions where the best `j + 1` batteries can end
+    let mut ends: Vec<Vec<usize>> = Vec::with_capacity(k);
+
+    for j in 0..k {
+        // right after the start, or after an end of the previous battery
+        let starts = ends
+            .last()
+            .map_or(vec![0], |ends| ends.iter().map(|end| end + 1).collect());
+
+        let mut candidates = Vec::new();
+        let mut from = 0;
+        for start in starts {
+            let stop = (start + max_skips).min(n - 1);
+            candidates
+                .extend((start.max(from)..=stop).filter(|&i| can_finish(n - 1 - i, k - j - 1)));
+            from = stop + 1;
+        }
+
+        let best = candidates.iter().map(|&i| joltages[i]).max()?;
+        candidates.retain(|&i| joltages[i] == best);
+        ends.push(candidates);
+    }
+
+    // back from any end of the last battery, to any end of the previous one
+    // close enough to it
+    let mut indices = vec![0; k];
+    let mut next = None;
+    for (j, ends) in ends.iter().enumerate().rev() {
+        let at = match next {
+            None => ends[0],
+            Some(next) => ends[ends.partition_point(|&end| end + max_skips + 1 < next)],
+        };
+        indices[j] = at;
+        next = Some(at);
+    }
+
+    Some(indices)
+}
+
+/// Tries every battery of joltage `digit` as one of those switched on, taking
+/// the largest from the stack on each side of it.
+fn select_with_digit(joltages: &[u8], k: usize, digit: u8) -> Option<Vec<usize>> {
+    let largest = |joltages: &[u8], k: usize| stack_select(joltages, k, |last, next| last < next);
+
+    let indices = largest(joltages, k);
+    if indices.iter().any(|&i| joltages[i] == digit) {
+        return Some(indices);
+    }
+
+    let candidates = joltages
+        .iter()
+        .enumerate()
+        .filter(|&(_, &joltage)| joltage == digit)
+        .flat_map(|(at, _)| {
+            let (before, after) = (at, joltages.len() - at - 1);
+
+            (k.saturating_sub(after + 1)..k.min(before + 1)).map(move |left| {
+                let right = largest(&joltages[at + 1..], k - 1 - left)
+                    .into_iter()
+                    .map(|i| at + 1 + i);
+
+                largest(&joltages[..at], left)
+                    .into_iter()
+                    .chain([at])
+                    .chain(right)
+                    .collect::<Vec<_>>()
+            })
+        });
+
+    candidates.max_by_key(|indices| indices.iter().map(|&i| joltages[i]).collect::<Vec<_>>())
+}
+
+/// Each bank with its switched on batteries highlighted, and its joltage.
+fn explain_text(banks: &[&str], selections: &[Selection], color: bool) -> String {
+    let (on, off) = if color {
+        ("\x1b[1;32m", "\x1b[0m")
+    } else {
+        ("[", "]")
+    };
+
+    banks
+        .iter()
+        .zip(selections)
+        .map(|(bank, selection)| {
+            let mut selected = selection.indices.iter().peekable();
+            let highlighted: String = bank
+                .chars()
+                .enumerate()
+                .map(|(i, joltage)| {
+                    if selected.next_if_eq(&&i).is_some() {
+                        format!("{on}{joltage}{off}")
+                    } else {
+                        joltage.to_string()
+                    }
+                })
+                .collect();
+
+            format!("{highlighted}  {}", selection.joltage)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The selections of every bank, on a single line of JSON.
+fn explain_json(part: Part, k: usize, banks: &[&str], selections: &[Selection]) -> String {
+    let part = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+
+    let banks = banks
+        .iter()
+        .zip(selections)
+        .map(|(bank, selection)| {
+            let indices: Vec<String> = selection.indices.iter().map(usize::to_string).collect();
+
+            // the joltage is a string, as it may not fit in a JSON number
+            format!(
+                r#"{{"bank":"{bank}","indices":[{}],"joltage":"{}"}}"#,
+                indices.join(","),
+                selection.joltage
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(r#"{{"part":{part},"batteries":{k},"banks":[{banks}]}}"#)
+}
+
+#[test]
+fn test_max_joltage_k() -> Result<()> {
+    let selection = max_joltage_k("818181911112111", 12)?;
+    assert_eq!(selection.indices, [0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    assert_eq!(selection.joltage, BigUint::from(888_911_112_111u64));
+
+    assert_eq!(max_joltage_k("811111111111119", 2)?.indices, [0, 14]);
+    assert_eq!(max_joltage_k("123", 0)?.joltage, BigUint::ZERO);
+    assert!(max_joltage_k("12345", 6).is_err());
+    assert!(max_joltage_k("12a45", 2).is_err());
+    assert!(select("12/45", 2, Mode::Smallest).is_err());
+
+    // too many digits for a `u128`
+    let bank = "9".repeat(50);
+    assert_eq!(
+        max_joltage_k(&bank, 40)?.joltage.to_string(),
+        "9".repeat(40)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_explain() -> Result<()> {
+    let banks = Day03::parse(Day03::EXAMPLE_INPUT_1)?;
+
+    let text = Day03::explain(&banks, Part::One, ExplainFormat::Text { color: false })?;
+    assert_eq!(
+        text.as_deref().and_then(|text| text.lines().nth(1)),
+        Some("[8]1111111111111[9]  89")
+    );
+
+    let text = Day03::explain(&banks, Part::Two, ExplainFormat::Text { color: true })?;
+    assert!(text.is_some_and(|text| text.starts_with("\x1b[1;32m9\x1b[0m")));
+
+    let json = Day03::explain(&banks[..1].to_vec(), Part::One, ExplainFormat::Json)?;
+    assert_eq!(
+        json.as_deref(),
+        Some(
+            r#"{"part":1,"batteries":2,"banks":[{"bank":"987654321111111","indices":[0,1],"joltage":"98"}]}"#
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+fn brute_force_select(bank: &str, k: usize, mode: Mode) -> Option<BigUint> {
+    let joltages = bank.as_bytes();
+    let n = joltages.len();
+
+    (0u32..1 << n)
+        .filter(|subset| subset.count_ones() as usize == k)
+        .map(|subset| {
+            let indices: Vec<usize> = (0..n).filter(|i| subset >> i & 1 == 1).collect();
+            Selection::new(joltages, indices)
+        })
+        .filter(|selection| match mode {
+            Mode::Largest | Mode::Smallest => true,
+            Mode::MaxSkipsInARow(max_skips) => {
+                let bounds: Vec<isize> = std::iter::once(-1)
+                    .chain(selection.indices.iter().map(|&i| i as isize))
+                    .chain([n as isize])
+                    .collect();
+                bounds
+                    .windows(2)
+                    .all(|w| (w[1] - w[0] - 1) as usize <= max_skips)
+            }
+            Mode::RequiredDigit(digit) => selection
+                .indices
+                .iter()
+                .any(|&i| joltages[i] == b'0' + digit),
+        })
+        .map(|selection| selection.joltage)
+        .reduce(|a, b| match mode {
+            Mode::Smallest => a.min(b),
+            _ => a.max(b),
+        })
+}
+
+#[test]
+fn test_select_modes() {
+    // a stack would never switch on the 7
+    assert_eq!(
+        select("9875", 2, Mode::MaxSkipsInARow(1))
+            .ok()
+            .map(|s| s.indices),
+        Some(vec![0, 2])
+    );
+
+    let mut rng = crate::rng::Xorshift::new(0x2025_1203);
+    let mut random = |bound: u64| rng.below(bound) as usize;
+
+    for _ in 0..300 {
+        let n = random(11);
+        let bank: String = (0..n)
+            .map(|_| char::from(b'0' + (random(4) * 3) as u8))
+            .collect();
+        let k = random(n as u64 + 1);
+
+        let modes = [
+            Mode::Largest,
+            Mode::Smallest,
+            Mode::MaxSkipsInARow(random(4)),
+            Mode::RequiredDigit((random(5) * 2) as u8),
+        ];
+
+        for mode in modes {
+            let selection = select(&bank, k, mode).ok();
+            if let Some(selection) = &selection {
+                assert_eq!(selection.indices.len(), k);
+                assert!(selection.indices.is_sorted_by(|a, b| a < b));
+            }
+
+            assert_eq!(
+                selection.map(|selection| selection.joltage),
+                brute_force_select(&bank, k, mode),
+                "bank `{bank}`, k = {k}, {mode:?}"
+            );
+        }
+    }
+}
+
+crate::solution!(Day03);
@@ -0,0 +1,891 @@
+use crate::{
+    Solution,
+    animation::{Frame, Shade},
+    grid::{ADJACENT, Edges, Grid, ORTHOGONAL, Point},
+    rng::Xorshift,
+    runner::{ExplainFormat, Part},
+};
+use anyhow::Result;
+use std::{cmp::Reverse, collections::VecDeque, fmt, mem};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "13";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "43";
+
+    type Input<'a> = Grid<Tile>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let diagram = Grid::from_str(Self::DAY, input, "`.` or `@`", |c| match c {
+            '.' => Some(Tile::Empty),
+            '@' => Some(Tile::RollOfPaper),
+            _ => None,
+        })?;
+
+        Ok(diagram)
+    }
+
+    fn part_one(diagram: &Self::Input<'_>) -> Result<usize> {
+        let rule = AccessRule::default();
+
+        Ok(diagram
+            .iter()
+            .filter(|&(position, tile)| {
+                *tile == Tile::RollOfPaper && rule.can_be_accessed(diagram, position)
+            })
+            .count())
+    }
+
+    fn part_two(diagram: &Self::Input<'_>) -> Result<usize> {
+        let waves = removal_waves(diagram, RemovalMode::Sequential, &AccessRule::default());
+        Ok(waves.iter().map(Vec::len).sum())
+    }
+
+    fn animate(diagram: &Self::Input<'_>) -> Result<Option<Vec<Frame>>> {
+        let waves = removal_waves(diagram, RemovalMode::Sequential, &AccessRule::default());
+        Ok(Some(removal_frames(diagram, &waves)))
+    }
+
+    /// The heat map of the neighbour counts.
+    fn pgm(diagram: &Self::Input<'_>) -> Result<Option<Vec<u8>>> {
+        let rule = AccessRule::default();
+        let counts = neighbour_counts(diagram, &rule);
+        Ok(Some(heat_map_pgm(&counts, rule.offsets.len())))
+    }
+
+    /// Part one shows the neighbour counts as a heat map, part two the stable
+    /// core left once every roll that can be is removed.
+    fn explain(
+        diagram: &Self::Input<'_>,
+        part: Part,
+        format: ExplainFormat,
+    ) -> Result<Option<String>> {
+        let rule = AccessRule::default();
+
+        let explanation = match (part, format) {
+            (Part::One, ExplainFormat::Text { color }) => {
+                let counts = neighbour_counts(diagram, &rule);
+                let accessible = counts
+                    .iter()
+                    .filter(|&(_, count)| count.is_some_and(|count| count <= rule.max_neighbours))
+                    .count();
+
+                format!(
+                    "{}{accessible} rolls of paper can be accessed",
+                    heat_map(&counts, Some(rule.max_neighbours), color)
+                )
+            }
+            (Part::One, ExplainFormat::Json) => {
+                let heat_map = heat_map(&neighbour_counts(diagram, &rule), None, false);
+                format!(r#"{{"part":1,"heat_map":[{}]}}"#, json_rows(&heat_map))
+            }
+            (Part::Two, ExplainFormat::Text { .. }) => {
+                let core = stable_core(diagram, &rule);
+                let sizes: Vec<String> = core
+                    .components
+                    .iter()
+                    .map(|c| c.len().to_string())
+                    .collect();
+
+                format!(
+                    "{}{} rolls of paper can't be removed, in {} connected component(s) ({})",
+                    core.survivors,
+                    core.size(),
+                    core.components.len(),
+                    sizes.join(", ")
+                )
+            }
+            (Part::Two, ExplainFormat::Json) => {
+                let core = stable_core(diagram, &rule);
+                let components = core
+                    .components
+                    .iter()
+                    .map(|component| {
+                        let points: Vec<String> = component
+                            .iter()
+                            .map(|(x, y)| format!("[{x},{y}]"))
+                            .collect();
+                        format!("[{}]", points.join(","))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                format!(
+                    r#"{{"part":2,"core":{{"size":{},"survivors":[{}],"components":[{components}]}}}}"#,
+                    core.size(),
+                    json_rows(&core.survivors.to_string())
+                )
+            }
+        };
+
+        Ok(Some(explanation))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    RollOfPaper,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::RollOfPaper => write!(f, "@"),
+        }
+    }
+}
+
+/// The positions around a roll of paper that get in the way of forklifts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The 4 positions sharing an edge.
+    VonNeumann,
+    /// The 8 positions sharing an edge or a corner.
+    Moore,
+    /// The positions at most `r` rows and columns away.
+    Radius(usize),
+    /// Any offsets `(dx, dy)`, each counting once even if given twice.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighbourhood {
+    fn offsets(&self) -> Vec<(isize, isize)> {
+        match self {
+            Neighbourhood::VonNeumann => ORTHOGONAL.to_vec(),
+            Neighbourhood::Moore => ADJACENT.to_vec(),
+            Neighbourhood::Radius(r) => {
+                let r = *r as isize;
+                (-r..=r)
+                    .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+                    .filter(|&offset| offset != (0, 0))
+                    .collect()
+            }
+            Neighbourhood::Custom(offsets) => {
+                let mut offsets = offsets.clone();
+                offsets.sort_unstable();
+                offsets.dedup();
+                offsets
+            }
+        }
+    }
+}
+
+/// When forklifts can access a roll of paper: when at most `max_neighbours`
+/// rolls are in its `neighbourhood`, which may wrap around the `edges` of the
+/// diagram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRule {
+    neighbourhood: Neighbourhood,
+    /// The offsets of the `neighbourhood`, computed once for every roll.
+    offsets: Vec<(isize, isize)>,
+    max_neighbours: usize,
+    edges: Edges,
+}
+
+/// The rule of the puzzle, fewer than 4 rolls in the 8 adjacent positions.
+impl Default for AccessRule {
+    fn default() -> Self {
+        Self::new(Neighbourhood::Moore, 3, Edges::Bounded)
+    }
+}
+
+impl AccessRule {
+    pub fn new(neighbourhood: Neighbourhood, max_neighbours: usize, edges: Edges) -> Self {
+        Self {
+            offsets: neighbourhood.offsets(),
+            neighbourhood,
+            max_neighbours,
+            edges,
+        }
+    }
+
+    pub fn neighbourhood(&self) -> &Neighbourhood {
+        &self.neighbourhood
+    }
+
+    pub fn max_neighbours(&self) -> usize {
+        self.max_neighbours
+    }
+
+    /// Whether the neighbourhood wraps around the edges of the diagram.
+    pub fn edges(&self) -> Edges {
+        self.edges
+    }
+
+    fn can_be_accessed(&self, diagram: &Grid<Tile>, position: Point) -> bool {
+        self.count_rolls(diagram, position) <= self.max_neighbours
+    }
+
+    fn count_rolls(&self, diagram: &Grid<Tile>, position: Point) -> usize {
+        self.offsets
+            .iter()
+            .filter_map(|&offset| diagram.offset(position, offset, self.edges))
+            .filter(|&p| diagram[p] == Tile::RollOfPaper)
+            .count()
+    }
+}
+
+/// How the rolls of paper that can be accessed are removed in each round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalMode {
+    /// All the rolls that can be accessed when the round starts, at once.
+    Simultaneous,
+    /// Row after row, so a removal also frees the rolls further along in the
+    /// same round.
+    Sequential,
+}
+
+/// Removes every roll of paper that can be accessed, then the ones that can
+/// be once those are gone, and so on, returning the rolls removed in each
+/// round, in row order.
+///
+/// Each roll keeps the count of rolls around it, decremented as they are
+/// removed, and is queued once it's low enough, so each roll is looked at a
+/// bounded number of times. The queue of a round is ordered by position, so a
+/// sequential round only frees rolls it hasn't gone past yet.
+pub fn removal_waves(
+    diagram: &Grid<Tile>,
+    mode: RemovalMode,
+    rule: &AccessRule,
+) -> Vec<Vec<Point>> {
+    let (width, height) = (diagram.width(), diagram.height());
+
+    // the rolls a removed roll was in the neighbourhood of, wrapping offsets
+    // reduced to the diagram so they wrap around each edge at most once
+    let reversed: Vec<_> = rule
+        .offsets
+        .iter()
+        .map(|&(dx, dy)| match rule.edges {
+            Edges::Bounded => (-dx, -dy),
+            Edges::Wrapping => (
+                (-dx).rem_euclid(width.max(1) as isize),
+                (-dy).rem_euclid(height.max(1) as isize),
+            ),
+        })
+        .collect();
+
+    let index = |(x, y): Point| y * width + x;
+    let point = |i: usize| (i % width, i / width);
+
+    // a queue per offset and edge it may wrap around, then one for the rolls
+    // that can be accessed right away
+    let queues = reversed.len() * 4 + 1;
+    let mut neighbours = Grid::filled(width, height, None);
+    let mut next = MergedQueues::new(queues);
+
+    for (position, &tile) in diagram.iter() {
+        if tile == Tile::RollOfPaper {
+            let count = rule.count_rolls(diagram, position);
+
+            neighbours[position] = Some(count);
+            if count <= rule.max_neighbours {
+                next.push(queues - 1, index(position));
+            }
+        }
+    }
+
+    let mut round = MergedQueues::new(queues);
+    let mut waves = Vec::new();
+
+    while !next.is_empty() {
+        // the round just done left its queues empty
+        mem::swap(&mut round, &mut next);
+        let mut wave = Vec::new();
+
+        while let Some(i) = round.pop() {
+            let (x, y) = point(i);
+            neighbours[(x, y)] = None;
+            wave.push((x, y));
+
+            for (o, &(dx, dy)) in reversed.iter().enumerate() {
+                let Some(p) = diagram.offset((x, y), (dx, dy), rule.edges) else {
+                    continue;
+                };
+
+                if let Some(count) = &mut neighbours[p] {
+                    *count -= 1;
+
+                    // only the rolls just getting low enough are queued, so none twice
+                    if *count == rule.max_neighbours {
+                        let wraps_x = p.0 as isize - x as isize != dx;
+                        let wraps_y = p.1 as isize - y as isize != dy;
+                        let queue = o * 4 + usize::from(wraps_x) + 2 * usize::from(wraps_y);
+
+                        match mode {
+                            RemovalMode::Sequential if index(p) > i => round.push(queue, index(p)),
+                            _ => next.push(queue, index(p)),
+                        }
+                    }
+                }
+            }
+        }
+
+        waves.push(wave);
+    }
+
+    waves
+}
+
+/// Positions popped in increasing order by merging queues that are each
+/// pushed in increasing order.
+///
+/// As rolls are removed in increasing order, the rolls a removal frees at
+/// the same offset, wrapping around the same edges, come in increasing order
+/// too, so a queue each keeps the removal linear for a given neighbourhood,
+/// where a heap would cost a logarithm per roll.
+struct MergedQueues {
+    queues: Vec<VecDeque<usize>>,
+    len: usize,
+}
+
+impl MergedQueues {
+    fn new(queues: usize) -> Self {
+        Self {
+            queues: vec![VecDeque::new(); queues],
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, queue: usize, i: usize) {
+        let queue = &mut self.queues[queue];
+        debug_assert!(queue.back().is_none_or(|&last| last < i));
+
+        queue.push_back(i);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let queue = self
+            .queues
+            .iter_mut()
+            .filter(|queue| !queue.is_empty())
+            .min_by_key(|queue| queue[0])?;
+
+        self.len -= 1;
+        queue.pop_front()
+    }
+}
+
+/// How many rolls are in the neighbourhood of each roll of paper, `None` where
+/// there's no roll.
+pub fn neighbour_counts(diagram: &Grid<Tile>, rule: &AccessRule) -> Grid<Option<usize>> {
+    let mut counts = Grid::filled(diagram.width(), diagram.height(), None);
+
+    for (position, &tile) in diagram.iter() {
+        if tile == Tile::RollOfPaper {
+            counts[position] = Some(rule.count_rolls(diagram, position));
+        }
+    }
+
+    counts
+}
+
+/// The neighbour `counts` as text, a digit per roll (`+` from 10 up) and `.`
+/// elsewhere. With `highlight`, the rolls with at most that many neighbours
+/// are coloured, or drawn as `x` like in the puzzle description without
+/// `color`.
+pub fn heat_map(counts: &Grid<Option<usize>>, highlight: Option<usize>, color: bool) -> String {
+    let mut heat_map = String::new();
+
+    for row in counts.rows() {
+        for &count in row {
+            let digit = match count {
+                None => '.',
+                Some(count @ 0..10) => char::from(b'0' + count as u8),
+                Some(_) => '+',
+            };
+
+            match (count, highlight) {
+                (Some(count), Some(max)) if count <= max && color => {
+                    heat_map += &format!("\x1b[1;32m{digit}\x1b[0m");
+                }
+                (Some(count), Some(max)) if count <= max => heat_map.push('x'),
+                _ => heat_map.push(digit),
+            }
+        }
+        heat_map.push('\n');
+    }
+
+    heat_map
+}
+
+/// The neighbour `counts` as a binary PGM image, a pixel per position: black
+/// where there's no roll, and brighter the more neighbours a roll has, up to
+/// `max_count`.
+pub fn heat_map_pgm(counts: &Grid<Option<usize>>, max_count: usize) -> Vec<u8> {
+    let mut pgm = format!("P5\n{} {}\n255\n", counts.width(), counts.height()).into_bytes();
+
+    pgm.extend(counts.rows().flatten().map(|count| match count {
+        None => 0,
+        Some(count) => (64 + 191 * count.min(&max_count) / max_count.max(1)) as u8,
+    }));
+
+    pgm
+}
+
+/// Each line of `text` as a JSON string, separated by commas.
+fn json_rows(text: &str) -> String {
+    text.lines()
+        .map(|line| format!(r#""{line}""#))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The rolls of paper left once every roll that can be is removed, which no
+/// removal order changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StableCore {
+    pub survivors: Grid<Tile>,
+    /// The groups of survivors linked through their neighbourhoods, largest
+    /// first, each in row order.
+    pub components: Vec<Vec<Point>>,
+}
+
+impl StableCore {
+    pub fn size(&self) -> usize {
+        self.components.iter().map(Vec::len).sum()
+    }
+}
+
+/// Removes every roll of paper that can be under `rule`, and groups the ones
+/// left into connected components, two rolls being linked when either is in
+/// the neighbourhood of the other.
+pub fn stable_core(diagram: &Grid<Tile>, rule: &AccessRule) -> StableCore {
+    let mut survivors = diagram.clone();
+    for wave in removal_waves(diagram, RemovalMode::Sequential, rule) {
+        for position in wave {
+            survivors[position] = Tile::Empty;
+        }
+    }
+
+    let links: Vec<_> = rule
+        .offsets
+        .iter()
+        .flat_map(|&(dx, dy)| [(dx, dy), (-dx, -dy)])
+        .collect();
+
+    let mut seen = Grid::filled(survivors.width(), survivors.height(), false);
+    let mut components = Vec::new();
+
+    for (start, &tile) in survivors.iter() {
+        if tile != Tile::RollOfPaper || seen[start] {
+            continue;
+        }
+
+        seen[start] = true;
+        let mut component = vec![start];
+        let mut stack = vec![start];
+
+        while let Some(position) = stack.pop() {
+            for &offset in &links {
+                if let Some(p) = survivors.offset(position, offset, rule.edges)
+                    && survivors[p] == Tile::RollOfPaper
+                    && !seen[p]
+                {
+                    seen[p] = true;
+                    component.push(p);
+                    stack.push(p);
+                }
+            }
+        }
+
+        component.sort_by_key(|&(x, y)| (y, x));
+        components.push(component);
+    }
+
+    // stable, so components of the same size stay in row order
+    components.sort_by_key(|component| Reverse(component.len()));
+
+    StableCore {
+        survivors,
+        components,
+    }
+}
+
+/// Draws the diagram before any removal, then each of the `waves` with its
+/// rolls highlighted, then the rolls left once nothing more can be removed.
+pub fn removal_frames(diagram: &Grid<Tile>, waves: &[Vec<Point>]) -> Vec<Frame> {
+    let mut grid = Grid::filled(diagram.width(), diagram.height(), Shade::Empty);
+    let mut left = 0;
+    for (position, &tile) in diagram.iter() {
+        if tile == Tile::RollOfPaper {
+            grid[position] = Shade::Filled;
+            left += 1;
+        }
+    }
+
+    let mut frames = vec![Frame {
+        grid: grid.clone(),
+        caption: format!("Round 0: {left} rolls of paper"),
+    }];
+    let mut previous: &[Point] = &[];
+
+    for (round, wave) in waves.iter().enumerate() {
+        for &position in previous {
+            grid[position] = Shade::Empty;
+        }
+        for &position in wave {
+            grid[position] = Shade::Highlighted;
+        }
+        left -= wave.len();
+        previous = wave;
+
+        frames.push(Frame {
+            grid: grid.clone(),
+            caption: format!("Round {}: {} removed, {left} left", round + 1, wave.len()),
+        });
+    }
+
+    for &position in previous {
+        grid[position] = Shade::Empty;
+    }
+    frames.push(Frame {
+        grid,
+        caption: format!("Done: {left} rolls of paper can't be removed"),
+    });
+
+    frames
+}
+
+/// Like [`removal_waves`], scanning the whole diagram again for each round,
+/// kept to compare against.
+pub fn removal_waves_by_rescanning(
+    diagram: &Grid<Tile>,
+    mode: RemovalMode,
+    rule: &AccessRule,
+) -> Vec<Vec<Point>> {
+    let mut diagram = diagram.clone();
+    let mut waves = Vec::new();
+
+    loop {
+        let wave = try_to_remove_rolls(&mut diagram, mode, rule);
+        if wave.is_empty() {
+            break;
+        }
+        waves.push(wave);
+    }
+
+    waves
+}
+
+fn try_to_remove_rolls(
+    diagram: &mut Grid<Tile>,
+    mode: RemovalMode,
+    rule: &AccessRule,
+) -> Vec<Point> {
+    // simultaneous rounds look at the diagram as it was when they started
+    let before = (mode == RemovalMode::Simultaneous).then(|| diagram.clone());
+    let mut removed = Vec::new();
+
+    for y in 0..diagram.height() {
+        for x in 0..diagram.width() {
+            let state = before.as_ref().unwrap_or(diagram);
+
+            if state[(x, y)] == Tile::RollOfPaper && rule.can_be_accessed(state, (x, y)) {
+                diagram[(x, y)] = Tile::Empty;
+                removed.push((x, y));
+            }
+        }
+    }
+
+    removed
+}
+
+/// A reproducible diagram where about `percent` of the positions hold a roll
+/// of paper, for tests and benchmarks.
+pub fn generate_diagram(width: usize, height: usize, percent: u64, seed: u64) -> Grid<Tile> {
+    let mut rng = Xorshift::new(seed);
+    let mut diagram = Grid::filled(width, height, Tile::Empty);
+
+    for y in 0..height {
+        for x in 0..width {
+            if rng.below(100) < percent {
+                diagram[(x, y)] = Tile::RollOfPaper;
+            }
+        }
+    }
+
+    diagram
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    // a single line without a trailing newline
+    assert_eq!(Day04::parse("@.@")?.width(), 3);
+
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_1)?;
+    assert_eq!(diagram.to_string(), Day04::EXAMPLE_INPUT_1);
+
+    assert!(Day04::parse("..@\n.@\n").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_removal_waves() -> Result<()> {
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_2)?;
+
+    // the rolls removed in each round of the puzzle description
+    let sizes = |mode| -> Vec<usize> {
+        removal_waves(&diagram, mode, &AccessRule::default())
+            .iter()
+            .map(Vec::len)
+            .collect()
+    };
+    assert_eq!(
+        sizes(RemovalMode::Simultaneous),
+        [13, 12, 7, 5, 2, 1, 1, 1, 1]
+    );
+    assert_eq!(sizes(RemovalMode::Sequential).iter().sum::<usize>(), 43);
+
+    for seed in 1..=20 {
+        let diagram = generate_diagram(30, 20, 40 + seed * 2, seed);
+
+        let rules = [
+            AccessRule::default(),
+            AccessRule::new(Neighbourhood::Radius(2), 11, Edges::Wrapping),
+        ];
+
+        for mode in [RemovalMode::Simultaneous, RemovalMode::Sequential] {
+            for rule in &rules {
+                assert_eq!(
+                    removal_waves(&diagram, mode, rule),
+                    removal_waves_by_rescanning(&diagram, mode, rule),
+                    "seed {seed}, {mode:?}, {rule:?}"
+                );
+            }
+        }
+    }
+
+    // offsets wider and taller than the grid, which wrap more than once
+    let rule = AccessRule::new(
+        Neighbourhood::Custom(vec![(-9, -2), (1, 0), (4, 7), (-1, 5)]),
+        1,
+        Edges::Wrapping,
+    );
+    for seed in 1..=50 {
+        for (width, height) in [(2, 3), (3, 2), (5, 4)] {
+            let diagram = generate_diagram(width, height, 70, seed);
+
+            for mode in [RemovalMode::Simultaneous, RemovalMode::Sequential] {
+                assert_eq!(
+                    removal_waves(&diagram, mode, &rule),
+                    removal_waves_by_rescanning(&diagram, mode, &rule),
+                    "seed {seed}, {width}x{height}, {mode:?}"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_removal_frames() -> Result<()> {
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_2)?;
+    let waves = removal_waves(&diagram, RemovalMode::Simultaneous, &AccessRule::default());
+    let frames = removal_frames(&diagram, &waves);
+    let count = |frame: &Frame, shade| frame.grid.iter().filter(|&(_, &s)| s == shade).count();
+
+    assert_eq!(frames.len(), waves.len() + 2);
+    assert_eq!(frames[0].grid.to_string(), diagram.to_string());
+    assert_eq!(frames[0].caption, "Round 0: 71 rolls of paper");
+
+    // the first round highlights the rolls of part one
+    assert_eq!(count(&frames[1], Shade::Highlighted), 13);
+    assert_eq!(count(&frames[1], Shade::Filled), 71 - 13);
+    assert_eq!(frames[1].caption, "Round 1: 13 removed, 58 left");
+    assert_eq!(count(&frames[2], Shade::Highlighted), 12);
+    assert_eq!(count(&frames[2], Shade::Filled), 71 - 13 - 12);
+
+    let last = frames.last().unwrap();
+    assert_eq!(count(last, Shade::Highlighted), 0);
+    assert_eq!(count(last, Shade::Filled), 71 - 43);
+    assert_eq!(last.caption, "Done: 28 rolls of paper can't be removed");
+
+    Ok(())
+}
+
+#[test]
+fn test_stable_core() -> Result<()> {
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_2)?;
+    let rule = AccessRule::default();
+
+    let core = stable_core(&diagram, &rule);
+    assert_eq!(core.size(), 71 - 43);
+    assert_eq!(core.components.len(), 1);
+    assert_eq!(core.components[0][..2], [(4, 3), (5, 3)]);
+
+    // two blocks only losing their corners, and a lone roll
+    let diagram = Day04::parse(
+        "\
+@@@@......
+@@@@.....@
+@@@@......
+@@@@......
+..........
+.....@@@@@
+.....@@@@@
+.....@@@@@
+.....@@@@@
+",
+    )?;
+    let core = stable_core(&diagram, &rule);
+    let sizes: Vec<usize> = core.components.iter().map(Vec::len).collect();
+    assert_eq!(sizes, [16, 12]);
+    assert_eq!(core.components[1][0], (1, 0));
+    assert_eq!(core.survivors[(0, 0)], Tile::Empty);
+    assert_eq!(core.survivors[(9, 1)], Tile::Empty);
+
+    Ok(())
+}
+
+#[test]
+fn test_heat_map() -> Result<()> {
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_1)?;
+    let counts = neighbour_counts(&diagram, &AccessRule::default());
+
+    let text = heat_map(&counts, None, false);
+    assert_eq!(text.lines().next(), Some("..33.3343."));
+    assert_eq!(text.lines().last(), Some("1.3.454.2."));
+    assert_eq!(text.matches(|c: char| matches!(c, '0'..='3')).count(), 13);
+
+    // the accessible rolls are marked even without colours
+    let marked = heat_map(&counts, Some(3), false);
+    assert_eq!(marked.lines().next(), Some("..xx.xx4x."));
+    assert_eq!(marked.matches('x').count(), 13);
+    let colored = heat_map(&counts, Some(3), true);
+    assert_eq!(colored.matches("\x1b[1;32m").count(), 13);
+
+    let crowded = neighbour_counts(
+        &Day04::parse("@@@@@\n@@@@@\n@@@@@\n@@@@@\n@@@@@\n")?,
+        &AccessRule::new(Neighbourhood::Radius(2), 3, Edges::Bounded),
+    );
+    assert_eq!(
+        heat_map(&crowded, None, false).lines().nth(2),
+        Some("+++++")
+    );
+
+    let pgm = heat_map_pgm(&counts, 8);
+    assert!(pgm.starts_with(b"P5\n10 10\n255\n"));
+    assert_eq!(pgm.len(), "P5\n10 10\n255\n".len() + 100);
+    // the top left corner is empty, the top right roll has 3 neighbours
+    assert_eq!(pgm[pgm.len() - 100], 0);
+    assert_eq!(pgm[pgm.len() - 100 + 8], (64 + 191 * 3 / 8) as u8);
+
+    Ok(())
+}
+
+#[test]
+fn test_access_rules() -> Result<()> {
+    let diagram = Day04::parse(Day04::EXAMPLE_INPUT_1)?;
+
+    let puzzle = AccessRule::default();
+    assert_eq!(puzzle.neighbourhood(), &Neighbourhood::Moore);
+    assert_eq!(puzzle.max_neighbours(), 3);
+    assert_eq!(puzzle.edges(), Edges::Bounded);
+
+    let rule = AccessRule::new;
+    let (bounded, wrapping) = (Edges::Bounded, Edges::Wrapping);
+    // rolls accessible right away, and removed in the end
+    let cases = [
+        (rule(Neighbourhood::Moore, 3, bounded), 13, 43),
+        (rule(Neighbourhood::Radius(1), 3, bounded), 13, 43),
+        (
+            rule(Neighbourhood::Custom(ADJACENT.to_vec()), 3, bounded),
+            13,
+            43,
+        ),
+        (rule(Neighbourhood::VonNeumann, 1, bounded), 11, 16),
+        (rule(Neighbourhood::Radius(2), 10, bounded), 20, 71),
+        (
+            // only looking right, so every row empties from its right end
+            rule(Neighbourhood::Custom(vec![(1, 0), (2, 0)]), 0, bounded),
+            11,
+            71,
+        ),
+        (
+            // the same, offsets given twice only counting once
+            rule(
+                Neighbourhood::Custom(vec![(2, 0), (1, 0), (2, 0)]),
+                0,
+                bounded,
+            ),
+            11,
+            71,
+        ),
+        // the rolls on the edges now have neighbours on the other side
+        (rule(Neighbourhood::Moore, 3, wrapping), 2, 3),
+    ];
+
+    for (rule, accessible, removed) in cases {
+        let count = diagram
+            .iter()
+            .filter(|&(p, &tile)| tile == Tile::RollOfPaper && rule.can_be_accessed(&diagram, p))
+            .count();
+
+        for mode in [RemovalMode::Simultaneous, RemovalMode::Sequential] {
+            let waves = removal_waves(&diagram, mode, &rule);
+            assert_eq!(
+                waves,
+                removal_waves_by_rescanning(&diagram, mode, &rule),
+                "{rule:?}"
+            );
+            if mode == RemovalMode::Simultaneous {
+                assert_eq!(waves.first().map_or(0, Vec::len), count, "{rule:?}");
+            }
+            assert_eq!(
+                waves.iter().map(Vec::len).sum::<usize>(),
+                removed,
+                "{rule:?}"
+            );
+        }
+        assert_eq!(count, accessible, "{rule:?}");
+    }
+
+    Ok(())
+}
+
+crate::solution!(Day04);
@@ -0,0 +1,91 @@
+use crate::{
+    Solution,
+    intervals::IntervalSet,
+    parse::{self, ParseError},
+};
+use anyhow::Result;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "3";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "14";
+
+    type Input<'a> = Database;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let Some((ranges, ingredients)) = input.split_once("\n\n") else {
+            let end = &input[input.trim_end().len()..];
+            let expected = "a blank line before the ingredient IDs";
+            return Err(ParseError::at(Self::DAY, input, end, expected).into());
+        };
+
+        let fresh = ranges
+            .lines()
+            .map(|r| parse::range(Self::DAY, input, r))
+            .collect::<Result<_, _>>()?;
+
+        let ingredients = ingredients
+            .lines()
+            .map(|id| parse::number(Self::DAY, input, id, "an ingredient ID"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Database { fresh, ingredients })
+    }
+
+    fn part_one(database: &Self::Input<'_>) -> Result<usize> {
+        let fresh_count = database
+            .ingredients
+            .iter()
+            .filter(|&&id| database.fresh.contains(id))
+            .count();
+
+        Ok(fresh_count)
+    }
+
+    fn part_two(database: &Self::Input<'_>) -> Result<u128> {
+        Ok(database.fresh.total_length())
+    }
+}
+
+#[derive(Debug)]
+pub struct Database {
+    /// The ingredient IDs the ranges say are fresh.
+    fresh: IntervalSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+crate::solution!(Day05);
@@ -0,0 +1,635 @@
+use crate::{
+    Solution,
+    parse::{self, ParseError},
+};
+use anyhow::{Context, Result, bail, ensure};
+use std::{fmt::Display, ops::Range};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "4277556";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "3263827";
+
+    type Input<'a> = Worksheets;
+    type PartOne = Number;
+    type PartTwo = Number;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let layout = Layout::from_input(input)?;
+
+        Ok(Worksheets {
+            horizontal: layout.read_horizontally(input)?,
+            vertical: layout.read_vertically(input)?,
+        })
+    }
+
+    fn part_one(worksheets: &Self::Input<'_>) -> Result<Number> {
+        worksheets.horizontal.grand_total()
+    }
+
+    fn part_two(worksheets: &Self::Input<'_>) -> Result<Number> {
+        worksheets.vertical.grand_total()
+    }
+}
+
+/// Fixed precision: every operation is checked, and overflows are reported.
+#[cfg(not(feature = "arbitrary_precision"))]
+type Number = i128;
+
+/// Arbitrary precision: only undefined operations, like a division by zero,
+/// are reported.
+#[cfg(feature = "arbitrary_precision")]
+type Number = num_bigint::BigInt;
+
+/// The same worksheet, read the way each part reads it.
+#[derive(Debug)]
+pub struct Worksheets {
+    horizontal: Worksheet,
+    vertical: Worksheet,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Worksheet {
+    problems: Vec<Problem>,
+    /// 1-based column where each problem starts, to point at it in errors.
+    columns: Vec<usize>,
+}
+
+impl Worksheet {
+    /// Reads `input` the way part one does.
+    pub fn read_horizontally(input: &str) -> Result<Self> {
+        Ok(Layout::from_input(input)?.read_horizontally(input)?)
+    }
+
+    /// Reads `input` the way part two does.
+    pub fn read_vertically(input: &str) -> Result<Self> {
+        Ok(Layout::from_input(input)?.read_vertically(input)?)
+    }
+
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+
+    fn grand_total<N: Arithmetic>(&self) -> Result<N> {
+        self.problems
+            .iter()
+            .zip(&self.columns)
+            .try_fold(N::from(0), |total, (problem, column)| {
+                let answer = problem
+                    .solve()
+                    .with_context(|| format!("problem at column {column} can't be solved"))?;
+
+                total
+                    .checked_add(&answer)
+                    .with_context(|| format!("grand total overflows at column {column}"))
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub op: Operator,
+    pub operands: Vec<u64>,
+}
+
+impl Problem {
+    /// Applies the operator to the operands, in the order they were read.
+    fn solve<N: Arithmetic>(&self) -> Result<N> {
+        let mut operands = self.operands.iter().map(|&n| N::from(n));
+        let first = operands.next().context("no operands")?;
+
+        operands.try_fold(first, |acc, operand| self.op.apply(acc, operand))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    Min,
+    Max,
+}
+
+const OPERATORS: [(&str, Operator); 8] = [
+    ("+", Operator::Add),
+    ("-", Operator::Subtract),
+    ("*", Operator::Multiply),
+    ("/", Operator::Divide),
+    ("%", Operator::Remainder),
+    ("^", Operator::Power),
+    ("min", Operator::Min),
+    ("max", Operator::Max),
+];
+
+impl Operator {
+    fn from_token(token: &str) -> Option<Self> {
+        OPERATORS
+            .iter()
+            .find(|(symbol, _)| *symbol == token)
+            .map(|&(_, op)| op)
+    }
+
+    pub fn symbol(self) -> &'static str {
+        OPERATORS
+            .iter()
+            .find(|&&(_, op)| op == self)
+            .map(|(symbol, _)| *symbol)
+            .expect("every operator is in the table")
+    }
+
+    fn apply<N: Arithmetic>(self, lhs: N, rhs: N) -> Result<N> {
+        let result = match self {
+            Operator::Add => lhs.checked_add(&rhs),
+            Operator::Subtract => lhs.checked_sub(&rhs),
+            Operator::Multiply => lhs.checked_mul(&rhs),
+            Operator::Divide if rhs == N::from(0) => bail!("division by zero"),
+            Operator::Divide => lhs.checked_div(&rhs),
+            Operator::Remainder if rhs == N::from(0) => bail!("remainder by zero"),
+            Operator::Remainder => lhs.checked_rem(&rhs),
+            Operator::Power => {
+                let exponent = rhs
+                    .to_u32()
+                    .with_context(|| format!("unsupported exponent {rhs}"))?;
+                lhs.checked_pow(exponent)
+            }
+            Operator::Min => Some(lhs.min(rhs)),
+            Operator::Max => Some(lhs.max(rhs)),
+        };
+
+        result.with_context(|| format!("`{}` overflows", self.symbol()))
+    }
+}
+
+/// Renders `problems` the way part one reads them: one number per line, each
+/// problem in its own columns.
+pub fn render_horizontally(problems: &[Problem]) -> Result<String> {
+    ensure!(!problems.is_empty(), "no problems to render");
+
+    let rows = problems[0].operands.len();
+    ensure!(
+        rows > 0 && problems.iter().all(|p| p.operands.len() == rows),
+        "every problem needs the same number of operands, one per line"
+    );
+
+    let mut lines = vec![Vec::new(); rows + 1];
+
+    for problem in problems {
+        let symbol = problem.op.symbol();
+        let width = problem
+            .operands
+            .iter()
+            .map(|n| n.to_string().len())
+            .chain([symbol.len()])
+            .max()
+            .unwrap_or_default();
+
+        for (line, n) in lines.iter_mut().zip(&problem.operands) {
+            line.push(format!("{n:>width$}"));
+        }
+        lines[rows].push(format!("{symbol:<width$}"));
+    }
+
+    Ok(lines.iter().map(|cells| cells.join(" ") + "\n").collect())
+}
+
+/// Renders `problems` the way part two reads them: one number per column,
+/// from right to left, with its most significant digit at the top.
+pub fn render_vertically(problems: &[Problem]) -> Result<String> {
+    ensure!(!problems.is_empty(), "no problems to render");
+    ensure!(
+        problems.iter().all(|p| !p.operands.is_empty()),
+        "every problem needs at least one operand"
+    );
+
+    let digits: Vec<Vec<Vec<char>>> = problems
+        .iter()
+        .map(|p| {
+            p.operands
+                .iter()
+                .map(|n| n.to_string().chars().collect())
+                .collect()
+        })
+        .collect();
+    let rows = digits
+        .iter()
+        .flatten()
+        .map(Vec::len)
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![Vec::new(); rows + 1];
+
+    for (problem, digits) in problems.iter().zip(&digits) {
+        let symbol = problem.op.symbol();
+        let width = digits.len().max(symbol.len());
+
+        for (row, line) in lines.iter_mut().take(rows).enumerate() {
+            // the first operand is the rightmost column
+            let cell: String = (0..width)
+                .rev()
+                .map(|i| {
+                    digits
+                        .get(i)
+                        .and_then(|d| d.get(row))
+                        .copied()
+                        .unwrap_or(' ')
+                })
+                .collect();
+            line.push(cell);
+        }
+        lines[rows].push(format!("{symbol:<width$}"));
+    }
+
+    Ok(lines.iter().map(|cells| cells.join(" ") + "\n").collect())
+}
+
+/// Checked integer arithmetic, returning `None` on overflow.
+trait Arithmetic: Sized + Ord + From<u64> + Display {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+    fn checked_rem(&self, rhs: &Self) -> Option<Self>;
+    fn checked_pow(&self, exponent: u32) -> Option<Self>;
+    fn to_u32(&self) -> Option<u32>;
+}
+
+impl Arithmetic for i128 {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        i128::checked_add(*self, *rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i128::checked_sub(*self, *rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        i128::checked_mul(*self, *rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        i128::checked_div(*self, *rhs)
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        i128::checked_rem(*self, *rhs)
+    }
+
+    fn checked_pow(&self, exponent: u32) -> Option<Self> {
+        i128::checked_pow(*self, exponent)
+    }
+
+    fn to_u32(&self) -> Option<u32> {
+        u32::try_from(*self).ok()
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl Arithmetic for num_bigint::BigInt {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        Some(self / rhs)
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        Some(self % rhs)
+    }
+
+    fn checked_pow(&self, exponent: u32) -> Option<Self> {
+        Some(self.pow(exponent))
+    }
+
+    fn to_u32(&self) -> Option<u32> {
+        u32::try_from(self).ok()
+    }
+}
+
+/// Worksheet lines, with the columns of each problem.
+///
+/// Problems are separated by columns that are blank on every line.
+#[derive(Debug)]
+struct Layout<'a> {
+    numbers: Vec<&'a str>,
+    problems: Vec<(Range<usize>, Operator)>,
+}
+
+impl<'a> Layout<'a> {
+    fn from_input(input: &'a str) -> Result<Self, ParseError> {
+        let mut numbers: Vec<&str> = input.lines().collect();
+
+        let operators = numbers
+            .pop()
+            .ok_or_else(|| error(input, input, "a line of operators"))?;
+        let first_line = numbers
+            .first()
+            .ok_or_else(|| error(input, operators, "a line of numbers"))?;
+
+        let width = first_line.len();
+
+        for line in numbers.iter().chain([&operators]) {
+            if let Some((i, _)) = line.char_indices().find(|(_, c)| !c.is_ascii()) {
+                return Err(error(input, &line[i..], "an ASCII character"));
+            }
+            if line.len() != width {
+                let at = &line[width.min(line.len())..];
+                return Err(error(input, at, &format!("a line of {width} columns")));
+            }
+        }
+
+        let is_blank = |column: usize| {
+            numbers
+                .iter()
+                .chain([&operators])
+                .all(|line| line.as_bytes()[column].is_ascii_whitespace())
+        };
+
+        let mut columns = Vec::new();
+        let mut start = None;
+
+        for column in 0..=width {
+            if column == width || is_blank(column) {
+                if let Some(start) = start.take() {
+                    columns.push(start..column);
+                }
+            } else if start.is_none() {
+                start = Some(column);
+            }
+        }
+
+        let problems = columns
+            .into_iter()
+            .map(|columns| {
+                let op = Self::operator(input, operators, &columns)?;
+                Ok((columns, op))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { numbers, problems })
+    }
+
+    fn operator(
+        input: &str,
+        operators: &str,
+        columns: &Range<usize>,
+    ) -> Result<Operator, ParseError> {
+        let mut tokens = operators[columns.clone()].split_whitespace();
+
+        let Some(token) = tokens.next() else {
+            return Err(error(
+                input,
+                &operators[columns.start..],
+                "an operator for this problem",
+            ));
+        };
+        if let Some(token) = tokens.next() {
+            return Err(error(input, token, "a single operator per problem"));
+        }
+
+        Operator::from_token(token).ok_or_else(|| {
+            let symbols: Vec<String> = OPERATORS.iter().map(|(s, _)| format!("`{s}`")).collect();
+            error(
+                input,
+                token,
+                &format!("an operator ({})", symbols.join(", ")),
+            )
+        })
+    }
+
+    /// One number per worksheet line.
+    fn read_horizontally(&self, input: &str) -> Result<Worksheet, ParseError> {
+        let problems = self
+            .problems
+            .iter()
+            .map(|(columns, op)| {
+                let operands = self
+                    .numbers
+                    .iter()
+                    .map(|line| {
+                        parse::number(Day06::DAY, input, line[columns.clone()].trim(), "a number")
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Problem { op: *op, operands })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Worksheet {
+            problems,
+            columns: self.columns(),
+        })
+    }
+
+    /// One number per worksheet column, read right-to-left, with its most
+    /// significant digit at the top.
+    fn read_vertically(&self, input: &str) -> Result<Worksheet, ParseError> {
+        let problems = self
+            .problems
+            .iter()
+            .map(|(columns, op)| {
+                let operands = columns
+                    .clone()
+                    .rev()
+                    .filter(|&column| !self.is_blank(column))
+                    .map(|column| self.column_number(input, column))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Problem { op: *op, operands })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Worksheet {
+            problems,
+            columns: self.columns(),
+        })
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        self.problems
+            .iter()
+            .map(|(columns, _)| columns.start + 1)
+            .collect()
+    }
+
+    /// Only the operator, when it's wider than the numbers, can leave some
+    /// columns of a problem without digits.
+    fn is_blank(&self, column: usize) -> bool {
+        self.numbers
+            .iter()
+            .all(|line| line.as_bytes()[column].is_ascii_whitespace())
+    }
+
+    fn column_number(&self, input: &str, column: usize) -> Result<u64, ParseError> {
+        let mut digits = String::new();
+
+        for line in &self.numbers {
+            match line.as_bytes()[column] {
+                digit @ b'0'..=b'9' => digits.push(digit as char),
+                c if c.is_ascii_whitespace() => {}
+                _ => return Err(error(input, &line[column..], "a digit")),
+            }
+        }
+
+        digits
+            .parse()
+            .map_err(|_| error(input, &self.numbers[0][column..], "a number in this column"))
+    }
+}
+
+fn error(input: &str, at: &str, expected: &str) -> ParseError {
+    ParseError::at(Day06::DAY, input, at, expected)
+}
+
+#[test]
+fn test_invalid_worksheet() {
+    let error = |input: &str| {
+        let error = Day06::parse(input).unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        (error.line, error.column, error.expected)
+    };
+
+    assert_eq!(
+        error("12 3\n45 6\n?  +\n"),
+        (
+            3,
+            1,
+            "an operator (`+`, `-`, `*`, `/`, `%`, `^`, `min`, `max`)".to_string()
+        )
+    );
+    assert_eq!(
+        error("12 3\n45 6\n* +\n"),
+        (3, 4, "a line of 4 columns".to_string())
+    );
+    assert_eq!(
+        error("12 3\n45 6\n*   \n"),
+        (3, 4, "an operator for this problem".to_string())
+    );
+    assert_eq!(
+        error("123 4\n456 7\n* + +\n"),
+        (3, 3, "a single operator per problem".to_string())
+    );
+    assert_eq!(error("12 3\n4x 6\n*  +\n"), (2, 2, "a number".to_string()));
+}
+
+#[test]
+fn test_operators() -> Result<()> {
+    let worksheets = Day06::parse(
+        "\
+17 9 20 5 2   7   7
+ 5 4  6 2 3  12 100
+-  / %  ^ ^ min max
+",
+    )?;
+
+    // 17 - 5, 9 / 4, 20 % 6, 5 ^ 2, 2 ^ 3, min(7, 12), max(7, 100)
+    assert_eq!(Day06::part_one(&worksheets)?, Number::from(156u64));
+
+    // 75 - 1, 94, 6 % 2, 52, 23, min(72, 1), max(70, 0, 1)
+    assert_eq!(Day06::part_two(&worksheets)?, Number::from(314u64));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+#[test]
+fn test_overflow() -> Result<()> {
+    let worksheets = Day06::parse("2 99999999999\n1 9          \n+ ^          \n")?;
+    let error = Day06::part_one(&worksheets).unwrap_err();
+
+    assert_eq!(error.to_string(), "problem at column 3 can't be solved");
+    assert_eq!(error.root_cause().to_string(), "`^` overflows");
+
+    Ok(())
+}
+
+#[cfg(feature = "arbitrary_precision")]
+#[test]
+fn test_arbitrary_precision() -> Result<()> {
+    let worksheets = Day06::parse("100\n 40\n^  \n")?;
+
+    assert_eq!(Day06::part_one(&worksheets)?, Number::from(10u64).pow(80));
+
+    Ok(())
+}
+
+#[test]
+fn test_render_round_trip() -> Result<()> {
+    let mut rng = crate::rng::Xorshift::new(0x2025_1206);
+    let mut random = |bound: u64| rng.below(bound);
+
+    for _ in 0..500 {
+        let rows = 1 + random(5) as usize;
+        let problems: Vec<Problem> = (0..1 + random(6))
+            .map(|_| Problem {
+                op: OPERATORS[random(OPERATORS.len() as u64) as usize].1,
+                operands: (0..rows)
+                    .map(|_| {
+                        let digits = 1 + random(12) as u32;
+                        random(10u64.pow(digits))
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let horizontal = render_horizontally(&problems)?;
+        assert_eq!(
+            Worksheet::read_horizontally(&horizontal)?.problems(),
+            problems,
+            "{horizontal}"
+        );
+
+        let vertical = render_vertically(&problems)?;
+        assert_eq!(
+            Worksheet::read_vertically(&vertical)?.problems(),
+            problems,
+            "{vertical}"
+        );
+    }
+
+    // same problems as the example, with every number aligned at the top
+    assert_eq!(
+        render_vertically(&Worksheet::read_vertically(Day06::EXAMPLE_INPUT_2)?.problems)?,
+        "\
+123 328 351 644
+ 45 64  287 23 
+  6 98   15 31 
+*   +   *   +  
+"
+    );
+
+    Ok(())
+}
+
+crate::solution!(Day06);
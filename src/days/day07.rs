@@ -0,0 +1,154 @@
+use crate::{Solution, grid::Grid};
+use anyhow::{Context, Result};
+use std::fmt;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "21";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "40";
+
+    type Input<'a> = Diagram;
+    type PartOne = i32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Diagram::from_input(input)
+    }
+
+    fn part_one(diagram: &Self::Input<'_>) -> Result<i32> {
+        let mut beam_path = vec![vec!['.'; diagram.grid.width()]; diagram.grid.height()];
+        beam_path[0][diagram.start] = '|';
+
+        let mut split_count = 0;
+
+        for (i, row) in diagram.grid.rows().enumerate().skip(1) {
+            beam_path[i] = beam_path[i - 1].clone();
+
+            for splitter in splitters(row) {
+                if beam_path[i - 2][splitter] == '|' {
+                    split_count += 1;
+                    beam_path[i][splitter] = '.';
+                    beam_path[i][splitter - 1] = '|';
+                    beam_path[i][splitter + 1] = '|';
+                }
+            }
+        }
+
+        Ok(split_count)
+    }
+
+    fn part_two(diagram: &Self::Input<'_>) -> Result<u64> {
+        let mut beam_path = vec![vec![0; diagram.grid.width()]; diagram.grid.height()];
+        beam_path[0][diagram.start] = 1;
+
+        for (i, row) in diagram.grid.rows().enumerate().skip(1) {
+            beam_path[i] = beam_path[i - 1].clone();
+
+            for splitter in splitters(row) {
+                if beam_path[i - 2][splitter] > 0 {
+                    let timelines = beam_path[i - 2][splitter];
+                    beam_path[i][splitter] = 0;
+                    beam_path[i][splitter - 1] += timelines;
+                    beam_path[i][splitter + 1] += timelines;
+                }
+            }
+        }
+
+        let timelines_count = beam_path[diagram.grid.height() - 1].iter().sum();
+
+        Ok(timelines_count)
+    }
+}
+
+#[derive(Debug)]
+pub struct Diagram {
+    grid: Grid<Tile>,
+    start: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Start,
+    Splitter,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::Start => write!(f, "S"),
+            Tile::Splitter => write!(f, "^"),
+        }
+    }
+}
+
+impl Diagram {
+    fn from_input(input: &str) -> Result<Self> {
+        let grid = Grid::from_str(Day07::DAY, input, "`.`, `S` or `^`", |c| match c {
+            '.' => Some(Tile::Empty),
+            'S' => Some(Tile::Start),
+            '^' => Some(Tile::Splitter),
+            _ => None,
+        })?;
+
+        let start = grid
+            .rows()
+            .next()
+            .and_then(|row| row.iter().position(|&tile| tile == Tile::Start))
+            .context("missing start point")?;
+
+        Ok(Self { grid, start })
+    }
+}
+
+fn splitters(row: &[Tile]) -> impl Iterator<Item = usize> {
+    row.iter()
+        .enumerate()
+        .filter(|&(_, &tile)| tile == Tile::Splitter)
+        .map(|(i, _)| i)
+}
+
+crate::solution!(Day07);
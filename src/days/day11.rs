@@ -0,0 +1,50 @@
+use crate::Solution;
+use anyhow::{Context, Result};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    const EXAMPLE_INPUT_1: &'static str = "\
+Part One example input
+";
+
+    const EXAMPLE_OUTPUT_1: &'static str = "Part One example output";
+
+    const EXAMPLE_INPUT_2: &'static str = "\
+Part Two example input
+";
+
+    const EXAMPLE_OUTPUT_2: &'static str = "Part Two example output";
+
+    type Input<'a> = &'a str;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String> {
+        let result = input
+            .lines()
+            .next()
+            .context("missing first line")?
+            .replace("input", "output");
+
+        Ok(result)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String> {
+        let result = input
+            .lines()
+            .next()
+            .context("missing first line")?
+            .replace("input", "output");
+
+        Ok(result)
+    }
+}
+
+crate::solution!(Day11);
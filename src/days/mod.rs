@@ -0,0 +1,45 @@
+use crate::runner::Entry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+/// Every day of the calendar, in order.
+pub const CALENDAR: [Entry; 12] = [
+    Entry::new::<day01::Day01>(),
+    Entry::new::<day02::Day02>(),
+    Entry::new::<day03::Day03>(),
+    Entry::new::<day04::Day04>(),
+    Entry::new::<day05::Day05>(),
+    Entry::new::<day06::Day06>(),
+    Entry::new::<day07::Day07>(),
+    Entry::new::<day08::Day08>(),
+    Entry::new::<day09::Day09>(),
+    Entry::new::<day10::Day10>(),
+    Entry::new::<day11::Day11>(),
+    Entry::new::<day12::Day12>(),
+];
+
+/// Looks up a day of the calendar.
+pub fn get(day: u8) -> Option<&'static Entry> {
+    CALENDAR.iter().find(|entry| entry.day == day)
+}
+
+#[test]
+fn test_calendar() {
+    for (i, entry) in CALENDAR.iter().enumerate() {
+        assert_eq!(entry.day as usize, i + 1);
+    }
+
+    assert!(get(0).is_none());
+    assert!(get(13).is_none());
+}
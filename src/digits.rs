@@ -0,0 +1,85 @@
+use anyhow::{Result, ensure};
+use std::{fmt, ops::RangeInclusive};
+
+/// Unsigned integers whose digits can be counted.
+pub trait Unsigned: Copy + Ord + fmt::Display + Into<u128> {
+    /// Converts back a value known to fit.
+    fn from_u128(n: u128) -> Self;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {
+        $(impl Unsigned for $t {
+            fn from_u128(n: u128) -> Self {
+                n as Self
+            }
+        })*
+    };
+}
+
+unsigned!(u8, u16, u32, u64, u128);
+
+/// The number of digits of `n` in `base`, `0` having one.
+pub fn digit_count<T: Unsigned>(n: T, base: u32) -> u32 {
+    n.into().checked_ilog(base.into()).map_or(1, |log| log + 1)
+}
+
+/// Splits `range` into consecutive ranges whose numbers all have the same
+/// number of decimal digits, e.g. `95..=1012` into `95..=99`, `100..=999` and
+/// `1000..=1012`.
+pub fn split_by_digit_count<T: Unsigned>(
+    range: RangeInclusive<T>,
+) -> Result<impl Iterator<Item = RangeInclusive<T>>> {
+    split_by_digit_count_in_base(range, 10)
+}
+
+/// Like [`split_by_digit_count`], with digits in `base`.
+pub fn split_by_digit_count_in_base<T: Unsigned>(
+    range: RangeInclusive<T>,
+    base: u32,
+) -> Result<impl Iterator<Item = RangeInclusive<T>>> {
+    let (start, end) = range.into_inner();
+    ensure!(base >= 2, "expected a base of at least 2, found {base}");
+    ensure!(
+        start <= end,
+        "the range `{start}-{end}` ends before it starts"
+    );
+
+    let wide_base = u128::from(base);
+    let (wide_start, wide_end) = (start.into(), end.into());
+
+    Ok(
+        (digit_count(start, base)..=digit_count(end, base)).map(move |length| {
+            // the one-digit numbers start at 0 rather than `base^0`
+            let low = match length {
+                1 => wide_start,
+                _ => wide_start.max(wide_base.pow(length - 1)),
+            };
+            let high = wide_base
+                .checked_pow(length)
+                .map_or(wide_end, |p| wide_end.min(p - 1));
+
+            T::from_u128(low)..=T::from_u128(high)
+        }),
+    )
+}
+
+#[test]
+fn test_split_by_digit_count() -> Result<()> {
+    let split =
+        |range| -> Result<Vec<RangeInclusive<u64>>> { Ok(split_by_digit_count(range)?.collect()) };
+
+    assert_eq!(split(95..=1012)?, [95..=99, 100..=999, 1000..=1012]);
+    assert_eq!(split(0..=9)?, [0..=9]);
+    assert_eq!(split(7..=7)?, [7..=7]);
+    assert_eq!(split(u64::MAX - 1..=u64::MAX)?, [u64::MAX - 1..=u64::MAX]);
+    assert_eq!(split(0..=u64::MAX)?.len(), 20);
+    assert!(split(RangeInclusive::new(1012, 95)).is_err());
+
+    let ranges: Vec<_> = split_by_digit_count_in_base(0u128..=u128::MAX, 2)?.collect();
+    assert_eq!(ranges.len(), 128);
+    assert_eq!(ranges[127], 1 << 127..=u128::MAX);
+    assert!(split_by_digit_count_in_base(0u8..=9, 1).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use crate::parse::ParseError;
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a [`Grid`], as `(x, y)` from the top left corner.
+pub type Point = (usize, usize);
+
+/// Offsets of the 4 cells sharing an edge with a cell.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8 cells sharing an edge or a corner with a cell.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// What lies past the edges of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing, the positions past them are outside of the grid.
+    Bounded,
+    /// The other side of the grid, as on a torus.
+    Wrapping,
+}
+
+/// A rectangular grid, its cells stored row after row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses `input`, one row per line, turning each character into a cell
+    /// with `cell` or pointing at it as not the `expected` one.
+    ///
+    /// Every row must have as many cells as the first one.
+    pub fn from_str(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut lines = input.lines().peekable();
+        let Some(first) = lines.peek() else {
+            return Err(ParseError::at(day, input, input, "a row of cells"));
+        };
+
+        let width = first.chars().count();
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+
+        for line in lines {
+            let row_expected = || format!("a row of {width} cells");
+
+            for (i, (at, c)) in line.char_indices().enumerate() {
+                if i == width {
+                    return Err(ParseError::at(day, input, &line[at..], row_expected()));
+                }
+
+                cells.push(
+                    cell(c).ok_or_else(|| ParseError::at(day, input, &line[at..], expected))?,
+                );
+            }
+
+            if cells.len() < width * (height + 1) {
+                return Err(ParseError::at(
+                    day,
+                    input,
+                    &line[line.len()..],
+                    row_expected(),
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// A grid of `width` by `height` cells, all equal to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        self.contains((x, y))
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        self.contains((x, y))
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't accept a width of 0, which has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// The position at `offset` from `point`, if it's inside the grid or
+    /// wrapped around its `edges`.
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize), edges: Edges) -> Option<Point> {
+        if edges == Edges::Wrapping {
+            if self.width == 0 || self.height == 0 {
+                return None;
+            }
+
+            let x = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+            let y = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+            return Some((x, y));
+        }
+
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The positions at `offsets` from `point` that are inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(point, offset, Edges::Bounded))
+    }
+
+    /// The up to 4 positions sharing an edge with `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// The up to 8 positions sharing an edge or a corner with `point`.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &ADJACENT)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+/// Writes the grid back as text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::from_str(0, input, "a digit", |c| c.to_digit(10))
+}
+
+#[test]
+fn test_grid() -> Result<(), ParseError> {
+    // no trailing newline
+    let grid = parse_digits("123\n456")?;
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get((2, 1)), Some(&6));
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid[(0, 1)], 4);
+
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+    let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+
+    assert_eq!(grid.offset((0, 0), (-1, 0), Edges::Bounded), None);
+    assert_eq!(grid.offset((0, 0), (-1, 0), Edges::Wrapping), Some((2, 0)));
+    assert_eq!(grid.offset((2, 1), (4, 1), Edges::Wrapping), Some((0, 0)));
+
+    assert_eq!(grid.to_string(), "123\n456\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_grid() {
+    let error = parse_digits("123\n45\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.expected, "a row of 3 cells");
+
+    let error = parse_digits("123\n4567\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+
+    let error = parse_digits("123\n4x6\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.expected.as_str()),
+        (2, 2, "a digit")
+    );
+
+    assert!(parse_digits("").is_err());
+}
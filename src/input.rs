@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at the directory holding the `day_NN.txt`
+/// puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input");
+
+/// Loads the puzzle input of `day`.
+///
+/// `path` overrides the default location, and `-` reads from stdin. Without
+/// it, the input is looked up in `$AOC_INPUT_DIR`, falling back to the
+/// `puzzle_input/` directory at the root of the project.
+pub fn load(day: u8, path: Option<&Path>) -> Result<String> {
+    let path = match path {
+        Some(path) if path == Path::new("-") => return read_stdin(),
+        Some(path) => path.to_owned(),
+        None => default_path(day),
+    };
+
+    fs::read_to_string(&path).with_context(|| {
+        format!(
+            "failed to read puzzle input `{}` (use `--input <path>` or set {INPUT_DIR_VAR})",
+            path.display()
+        )
+    })
+}
+
+/// Where the puzzle input of `day` is looked up when no path is given.
+pub fn default_path(day: u8) -> PathBuf {
+    input_path(day, env::var_os(INPUT_DIR_VAR))
+}
+
+fn input_path(day: u8, dir: Option<OsString>) -> PathBuf {
+    let dir = dir.map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from);
+    dir.join(format!("day_{day:02}.txt"))
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .context("failed to read puzzle input from stdin")?;
+
+    Ok(input)
+}
+
+#[test]
+fn test_input_path() {
+    assert_eq!(
+        input_path(5, Some("inputs".into())),
+        Path::new("inputs/day_05.txt")
+    );
+    assert_eq!(
+        input_path(12, None),
+        Path::new(DEFAULT_INPUT_DIR).join("day_12.txt")
+    );
+}
@@ -0,0 +1,246 @@
+use crate::digits::Unsigned;
+use std::{cmp, ops::RangeInclusive};
+
+/// A set of integers stored as sorted, disjoint ranges, merged as soon as they
+/// overlap or touch, e.g. `3..=5` and `6..=8` into `3..=8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Unsigned> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges of the set, in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds every number of `range`, merging it with the ranges it overlaps
+    /// or touches. An empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // the ranges from `first` to `last` (excluded) overlap or touch it
+        let first = self
+            .ranges
+            .partition_point(|r| (*r.end()).into().saturating_add(1) < start.into());
+        let last = self
+            .ranges
+            .partition_point(|r| (*r.start()).into() <= end.into().saturating_add(1));
+
+        if first < last {
+            start = cmp::min(start, *self.ranges[first].start());
+            end = cmp::max(end, *self.ranges[last - 1].end());
+        }
+
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Whether `value` is in the set, with a binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(i).is_some_and(|r| *r.start() <= value)
+    }
+
+    /// How many numbers are in the set.
+    ///
+    /// Panics if they don't fit in a `u128`, when the set holds every `u128`.
+    pub fn total_length(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|r| {
+                ((*r.end()).into() - (*r.start()).into())
+                    .checked_add(1)
+                    .expect("the length of the set should fit in a `u128`")
+            })
+            .sum()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = cmp::max(*a.start(), *b.start());
+            let end = cmp::min(*a.end(), *b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            // the range ending first can't overlap anything further
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // pieces of disjoint ranges are at least one number apart, as a gap of
+        // either set lies between them
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(*first.start()..=*last.end()))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// The numbers of `bounds` that aren't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (start, end) = bounds.into_inner();
+        let (start, end) = (start.into(), end.into());
+        let mut ranges = Vec::new();
+        // the first number not yet known to be in the set, `None` past `u128::MAX`
+        let mut next = Some(start);
+
+        for range in &self.ranges {
+            let Some(from) = next.filter(|&from| from <= end) else {
+                break;
+            };
+
+            let (range_start, range_end) = ((*range.start()).into(), (*range.end()).into());
+            if range_end < from {
+                continue;
+            }
+
+            if range_start > from {
+                let to = cmp::min(range_start - 1, end);
+                ranges.push(T::from_u128(from)..=T::from_u128(to));
+            }
+            next = range_end.checked_add(1);
+        }
+
+        if let Some(from) = next.filter(|&from| from <= end) {
+            ranges.push(T::from_u128(from)..=T::from_u128(end));
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Unsigned> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[test]
+fn test_insert() {
+    let mut set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18].into_iter().collect();
+    assert_eq!(set.ranges().collect::<Vec<_>>(), [3..=5, 10..=20]);
+
+    // touching ranges are merged too
+    set.insert(6..=8);
+    assert_eq!(set.ranges().collect::<Vec<_>>(), [3..=8, 10..=20]);
+    set.insert(9..=9);
+    assert_eq!(set.ranges().collect::<Vec<_>>(), [3..=20]);
+
+    set.insert(RangeInclusive::new(30, 25));
+    set.insert(0..=0);
+    set.insert(u64::MAX..=u64::MAX);
+    assert_eq!(
+        set.ranges().collect::<Vec<_>>(),
+        [0..=0, 3..=20, u64::MAX..=u64::MAX]
+    );
+    assert_eq!(set.total_length(), 20);
+
+    assert!(set.contains(0) && set.contains(3) && set.contains(20) && set.contains(u64::MAX));
+    assert!(!set.contains(1) && !set.contains(21));
+    assert!(!IntervalSet::new().contains(0u8));
+}
+
+#[test]
+fn test_set_operations() {
+    let mut rng = crate::rng::Xorshift::new(0x2025_1205);
+    let mut random = || rng.next_u64();
+
+    let mut random_set = || {
+        let mut set = IntervalSet::new();
+        let mut members = [false; 256];
+
+        for _ in 0..random() % 8 {
+            let start = random() as u8;
+            let end = start.saturating_add(random() as u8 % 40);
+            set.insert(start..=end);
+            members[usize::from(start)..=usize::from(end)].fill(true);
+        }
+
+        (set, members)
+    };
+
+    let assert_set = |set: &IntervalSet<u8>, members: [bool; 256]| {
+        for (n, &member) in members.iter().enumerate() {
+            assert_eq!(set.contains(n as u8), member, "{n} in {set:?}");
+        }
+        assert_eq!(
+            set.total_length(),
+            members.iter().filter(|&&m| m).count() as u128
+        );
+        // always merged as much as it can be
+        for pair in set.ranges.windows(2) {
+            assert!(
+                pair[0].end().saturating_add(1) < *pair[1].start(),
+                "{set:?}"
+            );
+        }
+    };
+
+    for _ in 0..200 {
+        let (a, in_a) = random_set();
+        let (b, in_b) = random_set();
+        assert_set(&a, in_a);
+
+        assert_set(&a.union(&b), std::array::from_fn(|n| in_a[n] || in_b[n]));
+        assert_set(
+            &a.intersection(&b),
+            std::array::from_fn(|n| in_a[n] && in_b[n]),
+        );
+        assert_set(
+            &a.difference(&b),
+            std::array::from_fn(|n| in_a[n] && !in_b[n]),
+        );
+
+        for bounds in b.ranges() {
+            assert_set(
+                &a.complement(bounds.clone()),
+                std::array::from_fn(|n| bounds.contains(&(n as u8)) && !in_a[n]),
+            );
+        }
+        assert_set(
+            &a.complement(0..=u8::MAX),
+            std::array::from_fn(|n| !in_a[n]),
+        );
+    }
+}
@@ -0,0 +1,106 @@
+pub mod animation;
+pub mod answers;
+pub mod days;
+pub mod digits;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod parse;
+mod rng;
+pub mod runner;
+pub mod timing;
+
+use crate::{
+    animation::Frame,
+    runner::{ExplainFormat, Part},
+};
+use anyhow::Result;
+use std::fmt::Display;
+
+/// A day of the calendar.
+///
+/// `parse` runs once and its output is shared by both parts, so each part only
+/// has to care about solving the puzzle.
+pub trait Solution {
+    const DAY: u8;
+
+    const EXAMPLE_INPUT_1: &'static str;
+    const EXAMPLE_OUTPUT_1: &'static str;
+    const EXAMPLE_INPUT_2: &'static str;
+    const EXAMPLE_OUTPUT_2: &'static str;
+
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo>;
+
+    /// Shows how the answer of `part` was found, for days that can tell.
+    fn explain(
+        _input: &Self::Input<'_>,
+        _part: Part,
+        _format: ExplainFormat,
+    ) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// The frames of an animation of how the puzzle gets solved, for days that
+    /// have something to show.
+    fn animate(_input: &Self::Input<'_>) -> Result<Option<Vec<Frame>>> {
+        Ok(None)
+    }
+
+    /// A picture of the puzzle as a binary PGM image, for days that can draw
+    /// one.
+    fn pgm(_input: &Self::Input<'_>) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+}
+
+/// Generates the example tests of a day, and the test checking the puzzle
+/// input against the accepted answers.
+///
+/// ```ignore
+/// crate::solution!(Day01);
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($day:ty) => {
+        #[test]
+        fn test_part_1() -> ::anyhow::Result<()> {
+            use $crate::Solution;
+
+            let input = <$day>::parse(<$day>::EXAMPLE_INPUT_1)?;
+
+            assert_eq!(
+                <$day>::part_one(&input)?.to_string(),
+                <$day>::EXAMPLE_OUTPUT_1
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_part_2() -> ::anyhow::Result<()> {
+            use $crate::Solution;
+
+            let input = <$day>::parse(<$day>::EXAMPLE_INPUT_2)?;
+
+            assert_eq!(
+                <$day>::part_two(&input)?.to_string(),
+                <$day>::EXAMPLE_OUTPUT_2
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_accepted_answers() -> ::anyhow::Result<()> {
+            $crate::answers::assert_accepted(&$crate::runner::Entry::new::<$day>())
+        }
+    };
+}
@@ -0,0 +1,162 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+/// Error pointing at the exact place of the puzzle input that couldn't be
+/// parsed.
+///
+/// ```text
+/// day 1: expected a distance, found `x` at line 3, column 2
+///   |
+/// 3 | Lx5
+///   |  ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// The whole offending line.
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error located at `at`, which must be a slice of `input`.
+    ///
+    /// An empty `at` points between two characters, e.g. at the end of a line
+    /// that is missing something.
+    pub fn at(day: u8, input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("`at` must be a slice of `input`");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    fn found(&self) -> String {
+        match self.snippet.chars().nth(self.column - 1) {
+            Some(c) => format!("`{c}`"),
+            None => "end of line".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "day {}: expected {}, found {} at line {}, column {}",
+            self.day,
+            self.expected,
+            self.found(),
+            self.line,
+            self.column,
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the number `s`, a slice of `input`, pointing at its first invalid
+/// character on failure.
+pub fn number<T: FromStr>(day: u8, input: &str, s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| {
+        let invalid = s
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && c == '-' && s.len() > 1)))
+            .map_or(0, |(i, _)| i);
+
+        ParseError::at(day, input, &s[invalid..], expected)
+    })
+}
+
+/// Parses the range `s`, a slice of `input` written `start-end`, rejecting it
+/// when it ends before it starts.
+pub fn range<T: FromStr + PartialOrd>(
+    day: u8,
+    input: &str,
+    s: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let Some((start, end)) = s.split_once('-') else {
+        return Err(ParseError::at(day, input, &s[s.len()..], "`-`"));
+    };
+
+    let start_value: T = number(day, input, start, "the start of a range")?;
+    let end_value: T = number(day, input, end, "the end of a range")?;
+
+    if end_value < start_value {
+        return Err(ParseError::at(
+            day,
+            input,
+            end,
+            format!("an end no lower than `{start}`"),
+        ));
+    }
+
+    Ok(start_value..=end_value)
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "L68\nLx5\nR14\n";
+    let line = input.lines().nth(1).unwrap();
+
+    let error = number::<i32>(1, input, &line[1..], "a distance").unwrap_err();
+    assert_eq!(
+        error,
+        ParseError {
+            day: 1,
+            line: 2,
+            column: 2,
+            snippet: "Lx5".to_string(),
+            expected: "a distance".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "\
+day 1: expected a distance, found `x` at line 2, column 2
+  |
+2 | Lx5
+  |  ^"
+    );
+
+    let error = ParseError::at(5, input, &line[3..], "`-`");
+    assert_eq!((error.line, error.column), (2, 4));
+    assert!(error.to_string().contains("found end of line"));
+}
+
+#[test]
+fn test_parse_range() {
+    let input = "3-5,14-10,7";
+    let ranges: Vec<&str> = input.split(',').collect();
+
+    assert_eq!(range::<u64>(2, input, ranges[0]), Ok(3..=5));
+
+    let error = range::<u64>(2, input, ranges[1]).unwrap_err();
+    assert_eq!(error.column, 8);
+    assert_eq!(error.expected, "an end no lower than `14`");
+
+    let error = range::<u64>(2, input, ranges[2]).unwrap_err();
+    assert_eq!((error.column, error.expected.as_str()), (12, "`-`"));
+}
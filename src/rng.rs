@@ -0,0 +1,24 @@
+/// A xorshift generator, to get reproducible random data for tests and
+/// generated inputs without a dependency.
+#[derive(Debug, Clone)]
+pub struct Xorshift(u64);
+
+impl Xorshift {
+    /// A seed of `0` would only ever give `0`, so it's replaced by `1`.
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number from `0` to `bound` excluded, slightly biased towards the
+    /// low ones, which test data doesn't mind.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Solution, animation::Frame, timing::Timing};
+use anyhow::{Result, bail};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("invalid part `{s}`, expected `1` or `2`"),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// How [`Solution::explain`] should show its explanation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainFormat {
+    /// For people, highlighting with ANSI colours or else with brackets.
+    Text { color: bool },
+    /// For tooling.
+    Json,
+}
+
+/// A type-erased [`Solution`], so every day can live in the same registry.
+#[derive(Debug)]
+pub struct Entry {
+    pub day: u8,
+    examples: [Example; 2],
+    solve: fn(&str, &[Part]) -> Result<Vec<String>>,
+    measure: fn(&str, &[Part], usize) -> Result<Measurement>,
+    explain: fn(&str, Part, ExplainFormat) -> Result<Option<String>>,
+    animate: fn(&str) -> Result<Option<Vec<Frame>>>,
+    pgm: fn(&str) -> Result<Option<Vec<u8>>>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            examples: [
+                Example {
+                    input: S::EXAMPLE_INPUT_1,
+                    output: S::EXAMPLE_OUTPUT_1,
+                },
+                Example {
+                    input: S::EXAMPLE_INPUT_2,
+                    output: S::EXAMPLE_OUTPUT_2,
+                },
+            ],
+            solve: solve::<S>,
+            measure: measure::<S>,
+            explain: explain::<S>,
+            animate: animate::<S>,
+            pgm: pgm::<S>,
+        }
+    }
+
+    pub fn example(&self, part: Part) -> &Example {
+        match part {
+            Part::One => &self.examples[0],
+            Part::Two => &self.examples[1],
+        }
+    }
+
+    /// Parses `input` once and returns the answer of each of the `parts`.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+        (self.solve)(input, parts)
+    }
+
+    /// Like [`Entry::solve`], timing the parsing and each of the `parts` over
+    /// `repeat` runs.
+    pub fn measure(&self, input: &str, parts: &[Part], repeat: usize) -> Result<Measurement> {
+        (self.measure)(input, parts, repeat)
+    }
+
+    /// Shows how the answer of `part` was found, or returns `None` if the day
+    /// can't tell.
+    pub fn explain(
+        &self,
+        input: &str,
+        part: Part,
+        format: ExplainFormat,
+    ) -> Result<Option<String>> {
+        (self.explain)(input, part, format)
+    }
+
+    /// The frames of an animation of how `input` gets solved, or `None` if the
+    /// day has nothing to show.
+    pub fn animate(&self, input: &str) -> Result<Option<Vec<Frame>>> {
+        (self.animate)(input)
+    }
+
+    /// A PGM image of `input`, or `None` if the day can't draw one.
+    pub fn pgm(&self, input: &str) -> Result<Option<Vec<u8>>> {
+        (self.pgm)(input)
+    }
+}
+
+/// The example given in the puzzle description of a part.
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    pub output: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub parse: Timing,
+    pub parts: Vec<(Part, String, Timing)>,
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<String>> {
+    let input = S::parse(input)?;
+
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::One => Ok(S::part_one(&input)?.to_string()),
+            Part::Two => Ok(S::part_two(&input)?.to_string()),
+        })
+        .collect()
+}
+
+fn measure<S: Solution>(input: &str, parts: &[Part], repeat: usize) -> Result<Measurement> {
+    let (input, parse) = Timing::measure(repeat, || S::parse(input))?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, timing) = match part {
+                Part::One => Timing::measure(repeat, || Ok(S::part_one(&input)?.to_string()))?,
+                Part::Two => Timing::measure(repeat, || Ok(S::part_two(&input)?.to_string()))?,
+            };
+            Ok((part, answer, timing))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Measurement { parse, parts })
+}
+
+fn explain<S: Solution>(input: &str, part: Part, format: ExplainFormat) -> Result<Option<String>> {
+    S::explain(&S::parse(input)?, part, format)
+}
+
+fn animate<S: Solution>(input: &str) -> Result<Option<Vec<Frame>>> {
+    S::animate(&S::parse(input)?)
+}
+
+fn pgm<S: Solution>(input: &str) -> Result<Option<Vec<u8>>> {
+    S::pgm(&S::parse(input)?)
+}
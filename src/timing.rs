@@ -0,0 +1,210 @@
+use anyhow::{Result, ensure};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Wall-clock statistics of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    /// Runs `f` `repeat` times, returning the output of the last run.
+    pub fn measure<T>(repeat: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Self)> {
+        ensure!(repeat > 0, "can't measure less than one run");
+
+        let mut durations = Vec::with_capacity(repeat);
+        let mut output = None;
+
+        for _ in 0..repeat {
+            let start = Instant::now();
+            let result = f()?;
+            durations.push(start.elapsed());
+            output = Some(result);
+        }
+
+        let output = output.expect("at least one run");
+
+        Ok((output, Self::from_durations(durations)))
+    }
+
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+
+        let mid = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[mid - 1] + durations[mid]) / 2
+        } else {
+            durations[mid]
+        };
+
+        Self {
+            median,
+            min: durations[0],
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+/// Human friendly rendering of a duration, e.g. `1.25 ms`.
+pub struct Elapsed(pub Duration);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+
+        let elapsed = match nanos {
+            0..1_000 => format!("{nanos} ns"),
+            1_000..1_000_000 => format!("{:.2} µs", nanos as f64 / 1e3),
+            1_000_000..1_000_000_000 => format!("{:.2} ms", nanos as f64 / 1e6),
+            _ => format!("{:.2} s", nanos as f64 / 1e9),
+        };
+
+        f.pad(&elapsed)
+    }
+}
+
+/// A row of the summary table.
+#[derive(Debug)]
+pub struct Row {
+    pub day: u8,
+    pub step: String,
+    pub answer: String,
+    pub timing: Timing,
+}
+
+const HEADER: [&str; 6] = ["Day", "Part", "Answer", "Median", "Min", "Max"];
+
+/// Renders `rows` as an aligned plain text table, or as a Markdown table.
+pub fn table(rows: &[Row], markdown: bool) -> String {
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                format!("{:02}", row.day),
+                row.step.clone(),
+                row.answer.clone(),
+                Elapsed(row.timing.median).to_string(),
+                Elapsed(row.timing.min).to_string(),
+                Elapsed(row.timing.max).to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADER.map(|h| h.chars().count());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: &[&str]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                // answers and durations are right aligned
+                if i >= 2 {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+
+        if markdown {
+            format!("| {} |", cells.join(" | "))
+        } else {
+            cells.join("  ").trim_end().to_string()
+        }
+    };
+
+    let mut lines = vec![line(&HEADER)];
+
+    let separator: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| {
+            if !markdown {
+                "-".repeat(width)
+            } else if i >= 2 {
+                format!("{}:", "-".repeat(width - 1))
+            } else {
+                "-".repeat(width)
+            }
+        })
+        .collect();
+    lines.push(line(
+        &separator.iter().map(String::as_str).collect::<Vec<_>>(),
+    ));
+
+    for row in &cells {
+        lines.push(line(&row.each_ref().map(String::as_str)));
+    }
+
+    lines.join("\n")
+}
+
+#[test]
+fn test_timing() {
+    let ms = Duration::from_millis;
+
+    assert_eq!(
+        Timing::from_durations(vec![ms(5), ms(1), ms(3)]),
+        Timing {
+            median: ms(3),
+            min: ms(1),
+            max: ms(5),
+        }
+    );
+    assert_eq!(
+        Timing::from_durations(vec![ms(4), ms(1), ms(2), ms(9)]).median,
+        ms(3)
+    );
+    assert_eq!(Elapsed(Duration::from_micros(1250)).to_string(), "1.25 ms");
+}
+
+#[test]
+fn test_table() {
+    let timing = Timing {
+        median: Duration::from_nanos(500),
+        min: Duration::from_nanos(400),
+        max: Duration::from_micros(12),
+    };
+    let rows = [
+        Row {
+            day: 1,
+            step: "Parse".to_string(),
+            answer: String::new(),
+            timing,
+        },
+        Row {
+            day: 1,
+            step: "Part One".to_string(),
+            answer: "1227775554".to_string(),
+            timing,
+        },
+    ];
+
+    assert_eq!(
+        table(&rows, false),
+        "\
+Day  Part          Answer  Median     Min       Max
+---  --------  ----------  ------  ------  --------
+01   Parse                 500 ns  400 ns  12.00 µs
+01   Part One  1227775554  500 ns  400 ns  12.00 µs"
+    );
+    assert_eq!(
+        table(&rows, true),
+        "\
+| Day | Part     |     Answer | Median |    Min |      Max |
+| --- | -------- | ---------: | -----: | -----: | -------: |
+| 01  | Parse    |            | 500 ns | 400 ns | 12.00 µs |
+| 01  | Part One | 1227775554 | 500 ns | 400 ns | 12.00 µs |"
+    );
+}